@@ -1,24 +1,34 @@
 mod use_async;
+mod use_async_effect;
+mod use_atom;
 mod use_before_unload;
 mod use_click_away;
 mod use_clipboard;
 mod use_cookie;
 mod use_counter;
+mod use_cycle;
 mod use_debounce;
 mod use_debounce_effect;
 mod use_debounce_state;
+mod use_debounced_value;
 mod use_default;
 mod use_drag;
 mod use_drop;
 mod use_effect_once;
 mod use_effect_update;
+mod use_effect_with_cmp;
+mod use_encrypted_storage;
 mod use_event;
 mod use_favicon;
+mod use_form;
 mod use_fullscreen;
 mod use_geolocation;
 mod use_hash;
+mod use_history_of;
+mod use_history_state;
 mod use_hovered;
 mod use_idle;
+mod use_indexed_db;
 mod use_infinite_scroll;
 mod use_interval;
 mod use_is_first_mount;
@@ -33,26 +43,33 @@ mod use_map;
 mod use_measure;
 mod use_media;
 mod use_media_query;
+mod use_memo_with_cmp;
 mod use_mount;
 mod use_permission;
 mod use_previous;
+mod use_priority_queue;
 mod use_queue;
 mod use_raf;
 mod use_raf_state;
+mod use_render_profiler;
 mod use_renders_count;
 mod use_scroll;
 mod use_scrolling;
 mod use_search_param;
+mod use_selection;
 mod use_session_storage;
 mod use_set;
 mod use_size;
 mod use_start_typing;
+mod use_state_machine;
 mod use_state_ptr_eq;
+mod use_storage;
 mod use_swipe;
 mod use_theme;
 mod use_throttle;
 mod use_throttle_effect;
 mod use_throttle_state;
+mod use_throttled_value;
 mod use_timeout;
 mod use_title;
 mod use_toggle;
@@ -61,30 +78,41 @@ mod use_update;
 mod use_virtual_list;
 mod use_visible;
 mod use_websocket;
+mod use_why_did_you_update;
 mod use_window_scroll;
 mod use_window_size;
 
 pub use use_async::*;
+pub use use_async_effect::*;
+pub use use_atom::*;
 pub use use_before_unload::*;
 pub use use_click_away::*;
 pub use use_clipboard::*;
 pub use use_cookie::*;
 pub use use_counter::*;
+pub use use_cycle::*;
 pub use use_debounce::*;
 pub use use_debounce_effect::*;
 pub use use_debounce_state::*;
+pub use use_debounced_value::*;
 pub use use_default::*;
 pub use use_drag::*;
 pub use use_drop::*;
 pub use use_effect_once::*;
 pub use use_effect_update::*;
+pub use use_effect_with_cmp::*;
+pub use use_encrypted_storage::*;
 pub use use_event::*;
 pub use use_favicon::*;
+pub use use_form::*;
 pub use use_fullscreen::*;
 pub use use_geolocation::*;
 pub use use_hash::*;
+pub use use_history_of::*;
+pub use use_history_state::*;
 pub use use_hovered::*;
 pub use use_idle::*;
+pub use use_indexed_db::*;
 pub use use_infinite_scroll::*;
 pub use use_interval::*;
 pub use use_is_first_mount::*;
@@ -99,26 +127,33 @@ pub use use_map::*;
 pub use use_measure::*;
 pub use use_media::*;
 pub use use_media_query::*;
+pub use use_memo_with_cmp::*;
 pub use use_mount::*;
 pub use use_permission::*;
 pub use use_previous::*;
+pub use use_priority_queue::*;
 pub use use_queue::*;
 pub use use_raf::*;
 pub use use_raf_state::*;
+pub use use_render_profiler::*;
 pub use use_renders_count::*;
 pub use use_scroll::*;
 pub use use_scrolling::*;
 pub use use_search_param::*;
+pub use use_selection::*;
 pub use use_session_storage::*;
 pub use use_set::*;
 pub use use_size::*;
 pub use use_start_typing::*;
+pub use use_state_machine::*;
 pub use use_state_ptr_eq::*;
+pub use use_storage::*;
 pub use use_swipe::*;
 pub use use_theme::*;
 pub use use_throttle::*;
 pub use use_throttle_effect::*;
 pub use use_throttle_state::*;
+pub use use_throttled_value::*;
 pub use use_timeout::*;
 pub use use_title::*;
 pub use use_toggle::*;
@@ -127,5 +162,6 @@ pub use use_update::*;
 pub use use_virtual_list::*;
 pub use use_visible::*;
 pub use use_websocket::*;
+pub use use_why_did_you_update::*;
 pub use use_window_scroll::*;
 pub use use_window_size::*;
@@ -0,0 +1,196 @@
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// Cleanup of a [`use_async_effect`] run, which may be async.
+/// Implemented for `()` and closures returning a future.
+pub trait AsyncTearDown: 'static {
+    /// Run the cleanup.
+    fn tear_down(self) -> Pin<Box<dyn Future<Output = ()>>>;
+}
+
+impl AsyncTearDown for () {
+    fn tear_down(self) -> Pin<Box<dyn Future<Output = ()>>> {
+        Box::pin(async {})
+    }
+}
+
+impl<F, Fut> AsyncTearDown for F
+where
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    fn tear_down(self) -> Pin<Box<dyn Future<Output = ()>>> {
+        Box::pin(self())
+    }
+}
+
+#[derive(Default)]
+struct AbortInner {
+    aborted: Cell<bool>,
+    wakers: RefCell<Vec<Waker>>,
+}
+
+/// Aborts a run of [`use_async_effect`] when dependencies change or the component unmounts.
+/// The future of an aborted run is dropped at its next `.await`.
+#[derive(Clone, Default)]
+pub struct AbortToken {
+    inner: Rc<AbortInner>,
+}
+
+impl AbortToken {
+    /// Whether the run has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.get()
+    }
+
+    /// Resolves once the run is aborted, e.g. to stop work that is not awaited.
+    pub async fn aborted(&self) {
+        Aborted { token: self }.await;
+    }
+
+    fn abort(&self) {
+        if !self.inner.aborted.replace(true) {
+            for waker in self.inner.wakers.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.inner.wakers.borrow_mut();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+}
+
+impl std::fmt::Debug for AbortToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AbortToken")
+            .field("aborted", &self.is_aborted())
+            .finish()
+    }
+}
+
+struct Aborted<'a> {
+    token: &'a AbortToken,
+}
+
+impl Future for Aborted<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_aborted() {
+            Poll::Ready(())
+        } else {
+            self.token.register(cx.waker());
+            Poll::Pending
+        }
+    }
+}
+
+/// Polls `future` until it completes or `token` is aborted.
+struct Abortable<Fut> {
+    future: Pin<Box<Fut>>,
+    token: AbortToken,
+}
+
+impl<Fut: Future> Future for Abortable<Fut> {
+    type Output = Option<Fut::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_aborted() {
+            return Poll::Ready(None);
+        }
+        self.token.register(cx.waker());
+        self.future.as_mut().poll(cx).map(Some)
+    }
+}
+
+/// This hook runs an async effect when dependencies change.
+/// The previous run is aborted first: its future is dropped at its next `.await`,
+/// or if it has completed, the cleanup it resolved to is run.
+/// The cleanup may be `()` or a closure returning a future.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::platform::time::sleep;
+/// # use yew::prelude::*;
+/// # use log::debug;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///     room: String,
+/// }
+///
+/// #[function_component(Chat)]
+/// fn chat(props: &Props) -> Html {
+///     let status = use_state(|| "Connecting...".to_string());
+///
+///     {
+///         let status = status.clone();
+///         use_async_effect(props.room.clone(), move |room, abort| {
+///             let room = room.clone();
+///             async move {
+///                 status.set("Connecting...".to_string());
+///                 // Pretend to connect, dropped here if the room changes meanwhile.
+///                 sleep(Duration::from_millis(500)).await;
+///                 status.set(format!("Connected to {}", room));
+///
+///                 move || async move {
+///                     debug!("Leaving {}, aborted: {}", room, abort.is_aborted());
+///                     sleep(Duration::from_millis(100)).await;
+///                 }
+///             }
+///         });
+///     }
+///
+///     html! {
+///         <p>{ &*status }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_async_effect<Callback, Fut, Cleanup, Dependents>(deps: Dependents, callback: Callback)
+where
+    Callback: FnOnce(&Dependents, AbortToken) -> Fut + 'static,
+    Fut: Future<Output = Cleanup> + 'static,
+    Cleanup: AsyncTearDown,
+    Dependents: PartialEq + 'static,
+{
+    use_effect_with(deps, move |deps| {
+        let token = AbortToken::default();
+        let cleanup = Rc::new(RefCell::new(None));
+        let future = Abortable {
+            future: Box::pin(callback(deps, token.clone())),
+            token: token.clone(),
+        };
+
+        {
+            let cleanup = cleanup.clone();
+            spawn_local(async move {
+                if let Some(tear_down) = future.await {
+                    *cleanup.borrow_mut() = Some(tear_down);
+                }
+            });
+        }
+
+        move || {
+            token.abort();
+            let tear_down: Option<Cleanup> = cleanup.borrow_mut().take();
+            if let Some(tear_down) = tear_down {
+                spawn_local(tear_down.tear_down());
+            }
+        }
+    });
+}
@@ -0,0 +1,466 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::{use_latest, use_update};
+
+/// Who is notified when a node changes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Subscriber {
+    Component(usize),
+    Selector(usize),
+}
+
+/// The state of an atom or a selector, keyed by the address of its static definition.
+#[derive(Default)]
+struct Node {
+    value: Option<Rc<dyn Any>>,
+    subscribers: HashMap<Subscriber, Rc<dyn Fn()>>,
+    /// The nodes a selector read when it was last computed.
+    dependencies: Vec<usize>,
+}
+
+thread_local! {
+    static NODES: RefCell<HashMap<usize, Node>> = RefCell::new(HashMap::new());
+    static NEXT_SUBSCRIBER: Cell<usize> = const { Cell::new(0) };
+}
+
+fn node_value<T: 'static>(key: usize) -> Option<Rc<T>> {
+    NODES.with(|nodes| {
+        nodes
+            .borrow()
+            .get(&key)
+            .and_then(|node| node.value.clone())
+            .map(|value| Rc::downcast(value).expect("atom value of a different type"))
+    })
+}
+
+/// Store a node value, returning its subscribers to notify.
+fn set_node_value(key: usize, value: Rc<dyn Any>) -> Vec<Rc<dyn Fn()>> {
+    NODES.with(|nodes| {
+        let mut nodes = nodes.borrow_mut();
+        let node = nodes.entry(key).or_default();
+        node.value = Some(value);
+        node.subscribers.values().cloned().collect()
+    })
+}
+
+fn subscribe(key: usize, subscriber: Subscriber, callback: Rc<dyn Fn()>) {
+    NODES.with(|nodes| {
+        nodes
+            .borrow_mut()
+            .entry(key)
+            .or_default()
+            .subscribers
+            .insert(subscriber, callback);
+    });
+}
+
+fn unsubscribe(key: usize, subscriber: Subscriber) {
+    NODES.with(|nodes| {
+        if let Some(node) = nodes.borrow_mut().get_mut(&key) {
+            node.subscribers.remove(&subscriber);
+        }
+    });
+}
+
+/// A piece of shared state that can be read with [`use_atom_value`], an [`Atom`] or a [`Selector`].
+pub trait Readable: 'static {
+    /// The type of the value.
+    type Value: 'static;
+
+    /// Get the current value.
+    fn get(&'static self) -> Rc<Self::Value>;
+
+    #[doc(hidden)]
+    fn key(&'static self) -> usize {
+        self as *const Self as *const () as usize
+    }
+}
+
+/// A piece of shared state, read and written by any component with [`use_atom`],
+/// without a context provider. Only components using the atom re-render when it changes.
+///
+/// Atoms must be declared as `static` items, not `const`, as they are identified by address.
+///
+/// ```rust
+/// use yew_hooks::prelude::*;
+///
+/// static COUNT: Atom<i32> = Atom::new(|| 0);
+/// ```
+pub struct Atom<T> {
+    default: fn() -> T,
+}
+
+impl<T> Atom<T>
+where
+    T: PartialEq + 'static,
+{
+    /// Define an atom with a function returning its initial value.
+    pub const fn new(default: fn() -> T) -> Self {
+        Self { default }
+    }
+
+    /// Set the value, notifying subscribers if it changed.
+    /// Can be used outside of components, e.g. in async tasks.
+    pub fn set(&'static self, value: T) {
+        if node_value::<T>(self.key()).is_some_and(|current| *current == value) {
+            return;
+        }
+        for subscriber in set_node_value(self.key(), Rc::new(value)) {
+            subscriber();
+        }
+    }
+
+    /// Reset to the initial value.
+    pub fn reset(&'static self) {
+        self.set((self.default)());
+    }
+}
+
+impl<T> Readable for Atom<T>
+where
+    T: PartialEq + 'static,
+{
+    type Value = T;
+
+    fn get(&'static self) -> Rc<T> {
+        match node_value(self.key()) {
+            Some(value) => value,
+            None => {
+                let value = Rc::new((self.default)());
+                set_node_value(self.key(), value.clone());
+                value
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Atom<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Atom").finish_non_exhaustive()
+    }
+}
+
+/// Reads atoms and selectors while computing a [`Selector`], recording them as dependencies.
+pub struct SelectorGetter {
+    dependencies: RefCell<Vec<usize>>,
+}
+
+impl SelectorGetter {
+    /// Get the current value of an atom or a selector.
+    pub fn get<R>(&self, readable: &'static R) -> Rc<R::Value>
+    where
+        R: Readable,
+    {
+        let key = readable.key();
+        let mut dependencies = self.dependencies.borrow_mut();
+        if !dependencies.contains(&key) {
+            dependencies.push(key);
+        }
+        drop(dependencies);
+        readable.get()
+    }
+}
+
+/// State derived from atoms and other selectors, recomputed when they change.
+/// Read it with [`use_atom_value`]; subscribers only re-render if the derived value changes.
+///
+/// Selectors must be declared as `static` items, not `const`, and must not depend on themselves.
+///
+/// ```rust
+/// use yew_hooks::prelude::*;
+///
+/// static COUNT: Atom<i32> = Atom::new(|| 0);
+/// static DOUBLED: Selector<i32> = Selector::new(|get| *get.get(&COUNT) * 2);
+/// ```
+pub struct Selector<T> {
+    compute: fn(&SelectorGetter) -> T,
+}
+
+impl<T> Selector<T>
+where
+    T: PartialEq + 'static,
+{
+    /// Define a selector with a function computing its value from atoms and selectors.
+    pub const fn new(compute: fn(&SelectorGetter) -> T) -> Self {
+        Self { compute }
+    }
+
+    /// Compute the value, subscribing to the dependencies read.
+    fn compute(&'static self) -> Rc<T> {
+        let getter = SelectorGetter {
+            dependencies: RefCell::new(vec![]),
+        };
+        let value = Rc::new((self.compute)(&getter));
+        let dependencies = getter.dependencies.into_inner();
+
+        let key = self.key();
+        let previous = NODES.with(|nodes| {
+            std::mem::replace(
+                &mut nodes.borrow_mut().entry(key).or_default().dependencies,
+                dependencies.clone(),
+            )
+        });
+        for dependency in previous {
+            if !dependencies.contains(&dependency) {
+                unsubscribe(dependency, Subscriber::Selector(key));
+            }
+        }
+        for dependency in dependencies {
+            subscribe(
+                dependency,
+                Subscriber::Selector(key),
+                Rc::new(move || self.recompute()),
+            );
+        }
+
+        value
+    }
+
+    /// Recompute after a dependency changed, notifying subscribers if the value changed.
+    fn recompute(&'static self) {
+        let value = self.compute();
+        if node_value::<T>(self.key()).is_some_and(|current| current == value) {
+            return;
+        }
+        for subscriber in set_node_value(self.key(), value) {
+            subscriber();
+        }
+    }
+}
+
+impl<T> Readable for Selector<T>
+where
+    T: PartialEq + 'static,
+{
+    type Value = T;
+
+    fn get(&'static self) -> Rc<T> {
+        match node_value(self.key()) {
+            Some(value) => value,
+            None => {
+                let value = self.compute();
+                set_node_value(self.key(), value.clone());
+                value
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Selector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Selector").finish_non_exhaustive()
+    }
+}
+
+/// State handle for the [`use_atom`] hook.
+pub struct UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    value: Rc<T>,
+    atom: &'static Atom<T>,
+}
+
+impl<T> UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    /// Set the value, re-rendering the components using the atom if it changed.
+    pub fn set(&self, value: T) {
+        self.atom.set(value);
+    }
+
+    /// Set the value computed from the current one.
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&T) -> T,
+    {
+        self.atom.set(f(&self.atom.get()));
+    }
+
+    /// Reset to the initial value.
+    pub fn reset(&self) {
+        self.atom.reset();
+    }
+}
+
+impl<T> Deref for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> Clone for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            atom: self.atom,
+        }
+    }
+}
+
+impl<T> PartialEq for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && std::ptr::eq(self.atom, other.atom)
+    }
+}
+
+impl<T> fmt::Debug for UseAtomHandle<T>
+where
+    T: PartialEq + fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseAtomHandle")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// This hook is used to read and write an [`Atom`] shared by components.
+/// The component re-renders when the atom changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// static COUNT: Atom<i32> = Atom::new(|| 0);
+///
+/// #[function_component(Counter)]
+/// fn counter() -> Html {
+///     let count = use_atom(&COUNT);
+///
+///     let onclick = {
+///         let count = count.clone();
+///         Callback::from(move |_| count.update(|count| count + 1))
+///     };
+///
+///     html! {
+///         <button {onclick}>{ "Clicked " }{ *count }{ " times" }</button>
+///     }
+/// }
+///
+/// #[function_component(Total)]
+/// fn total() -> Html {
+///     let count = use_atom_value(&COUNT);
+///
+///     html! {
+///         <p>{ "Total: " }{ *count }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_atom<T>(atom: &'static Atom<T>) -> UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    let value = use_atom_value(atom);
+
+    UseAtomHandle { value, atom }
+}
+
+/// This hook is used to read an [`Atom`] or a [`Selector`].
+/// The component re-renders when its value changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// static ITEMS: Atom<Vec<String>> = Atom::new(Vec::new);
+/// static ITEM_COUNT: Selector<usize> = Selector::new(|get| get.get(&ITEMS).len());
+///
+/// #[function_component(Badge)]
+/// fn badge() -> Html {
+///     // Only re-renders when the number of items changes.
+///     let count = use_atom_value(&ITEM_COUNT);
+///
+///     html! {
+///         <span>{ *count }</span>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_atom_value<R>(readable: &'static R) -> Rc<R::Value>
+where
+    R: Readable,
+{
+    let update = use_update();
+    let update = use_latest(update);
+    let value = readable.get();
+
+    {
+        let value = value.clone();
+        use_effect_with(readable.key(), move |key| {
+            let key = *key;
+            let subscriber = Subscriber::Component(NEXT_SUBSCRIBER.with(|next| {
+                let id = next.get();
+                next.set(id + 1);
+                id
+            }));
+            let update = Rc::new(move || (*update.current())());
+            subscribe(key, subscriber, update.clone());
+            // The value may have changed between rendering and subscribing.
+            if !Rc::ptr_eq(&value, &readable.get()) {
+                update();
+            }
+
+            move || unsubscribe(key, subscriber)
+        });
+    }
+
+    value
+}
+
+/// This hook is used to write an [`Atom`] without subscribing to it,
+/// so the component does not re-render when the atom changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// static THEME: Atom<String> = Atom::new(|| "light".to_string());
+///
+/// #[function_component(ThemeSwitcher)]
+/// fn theme_switcher() -> Html {
+///     let set_theme = use_set_atom(&THEME);
+///
+///     let onclick = Callback::from(move |_| set_theme.emit("dark".to_string()));
+///
+///     html! {
+///         <button {onclick}>{ "Dark mode" }</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_set_atom<T>(atom: &'static Atom<T>) -> Callback<T>
+where
+    T: PartialEq + 'static,
+{
+    (*use_memo((), move |_| Callback::from(move |value: T| atom.set(value)))).clone()
+}
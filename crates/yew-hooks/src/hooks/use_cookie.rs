@@ -1,11 +1,45 @@
+use std::collections::HashMap;
+use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
-use gloo::utils::document;
+use gloo::events::EventListener;
+use gloo::utils::{document, window};
 use serde::{Deserialize, Serialize};
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
 use yew::prelude::*;
 
+use super::{use_interval, use_latest};
+
+/// Interval of polling `document.cookie` for changes,
+/// when the Cookie Store API is not available.
+const POLL_INTERVAL: u32 = 1000;
+
+/// Error returned when a cookie cannot be set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CookieError {
+    /// The value could not be serialized to JSON.
+    Serialize(String),
+    /// `HttpOnly` cookies can only be set by the server.
+    HttpOnly,
+    /// Browsers reject `SameSite=None` cookies without the `Secure` attribute.
+    SameSiteNoneWithoutSecure,
+}
+
+impl fmt::Display for CookieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(message) => write!(f, "failed to serialize value: {}", message),
+            Self::HttpOnly => write!(f, "HttpOnly cookies cannot be set from script"),
+            Self::SameSiteNoneWithoutSecure => {
+                write!(f, "SameSite=None cookies must also be secure")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CookieError {}
+
 /// State handle for the [`use_cookie`] hook.
 pub struct UseCookieHandle<T> {
     inner: UseStateHandle<Option<T>>,
@@ -28,36 +62,70 @@ impl<T> UseCookieHandle<T> {
     }
 
     /// Set a `value` for the specified key with additional cookie attributes.
+    /// Invalid attributes are logged as a warning and the cookie is written anyway,
+    /// see [`try_set_with_attributes`](Self::try_set_with_attributes) to check them instead.
     pub fn set_with_attributes(&self, value: T, attributes: CookieAttributes)
     where
         T: Serialize + Clone,
     {
-        if let Ok(cookie_str) = serde_json::to_string(&value) {
-            // URL encode the value
-            let encoded_value = urlencoding::encode(&cookie_str);
-            let mut cookie = format!("{}={}", self.key, encoded_value);
+        if let Err(e) = attributes.validate() {
+            log::warn!("cookie {}: {}", self.key, e);
+        }
+        if let Err(e) = self.write(value, &attributes) {
+            log::warn!("cookie {}: {}", self.key, e);
+        }
+    }
 
-            if let Some(max_age) = attributes.max_age {
-                cookie.push_str(&format!("; max-age={}", max_age));
-            }
-            if let Some(path) = &attributes.path {
-                cookie.push_str(&format!("; path={}", path));
-            }
-            if let Some(domain) = &attributes.domain {
-                cookie.push_str(&format!("; domain={}", domain));
-            }
-            if attributes.secure {
-                cookie.push_str("; secure");
-            }
-            // Note: HttpOnly cookies cannot be set or accessed via JavaScript
-            // They can only be set server-side via HTTP headers
-            if let Some(same_site) = &attributes.same_site {
-                cookie.push_str(&format!("; SameSite={}", same_site));
-            }
+    /// Set a `value` for the specified key with additional cookie attributes,
+    /// returning an error if the value cannot be serialized or the attributes are invalid.
+    pub fn try_set_with_attributes(
+        &self,
+        value: T,
+        attributes: CookieAttributes,
+    ) -> Result<(), CookieError>
+    where
+        T: Serialize + Clone,
+    {
+        attributes.validate()?;
+        self.write(value, &attributes)
+    }
 
-            set_cookie(&cookie);
-            self.inner.set(Some(value));
+    /// Serialize and write a `value` with the attributes as they are.
+    fn write(&self, value: T, attributes: &CookieAttributes) -> Result<(), CookieError>
+    where
+        T: Serialize + Clone,
+    {
+        let cookie_str =
+            serde_json::to_string(&value).map_err(|e| CookieError::Serialize(e.to_string()))?;
+        // URL encode the value
+        let encoded_value = urlencoding::encode(&cookie_str);
+        let mut cookie = format!("{}={}", self.key, encoded_value);
+
+        if let Some(expires) = attributes.expires {
+            let expires: String = js_sys::Date::new(&JsValue::from_f64(expires))
+                .to_utc_string()
+                .into();
+            cookie.push_str(&format!("; expires={}", expires));
+        }
+        if let Some(max_age) = attributes.max_age {
+            cookie.push_str(&format!("; max-age={}", max_age));
+        }
+        if let Some(path) = &attributes.path {
+            cookie.push_str(&format!("; path={}", path));
         }
+        if let Some(domain) = &attributes.domain {
+            cookie.push_str(&format!("; domain={}", domain));
+        }
+        if attributes.secure {
+            cookie.push_str("; secure");
+        }
+        if let Some(same_site) = &attributes.same_site {
+            cookie.push_str(&format!("; SameSite={}", same_site));
+        }
+
+        set_cookie(&cookie);
+        self.inner.set(Some(value));
+        Ok(())
     }
 
     /// Delete a key and its stored value.
@@ -95,9 +163,13 @@ where
     }
 }
 
-/// Attributes for setting cookies
+/// Attributes for setting cookies, built from [`Default`] with the `with_*` methods.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct CookieAttributes {
+    /// Expiry date of the cookie, in milliseconds since the Unix epoch.
+    /// `max_age` takes precedence if both are set.
+    pub expires: Option<f64>,
     /// Maximum age of the cookie in seconds
     pub max_age: Option<i64>,
     /// Path for which the cookie is valid
@@ -106,13 +178,62 @@ pub struct CookieAttributes {
     pub domain: Option<String>,
     /// If true, the cookie is only sent over HTTPS
     pub secure: bool,
-    /// Note: HttpOnly cannot be set via JavaScript - it's only for server-side cookies
-    /// This field is kept for API consistency but has no effect
+    /// HttpOnly cannot be set via JavaScript - it's only for server-side cookies.
+    /// Setting it makes [`validate`](Self::validate) fail with [`CookieError::HttpOnly`].
     pub http_only: bool,
     /// SameSite attribute for the cookie
     pub same_site: Option<SameSite>,
 }
 
+impl CookieAttributes {
+    /// Expire the cookie at `expires`, in milliseconds since the Unix epoch.
+    pub fn with_expires(mut self, expires: f64) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Expire the cookie after `max_age` seconds.
+    pub fn with_max_age(mut self, max_age: i64) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Only send the cookie for `path`.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Send the cookie to `domain`.
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Only send the cookie over HTTPS.
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Set the SameSite attribute.
+    pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Check the attributes for combinations browsers would reject.
+    pub fn validate(&self) -> Result<(), CookieError> {
+        if self.http_only {
+            return Err(CookieError::HttpOnly);
+        }
+        if matches!(self.same_site, Some(SameSite::None)) && !self.secure {
+            return Err(CookieError::SameSiteNoneWithoutSecure);
+        }
+        Ok(())
+    }
+}
+
 /// SameSite attribute values
 #[derive(Debug, Clone)]
 pub enum SameSite {
@@ -152,18 +273,92 @@ fn get_cookie_string() -> Option<String> {
         .and_then(|v| v.as_string())
 }
 
+/// Parse a cookie string into `(key, value)` pairs, with values not yet decoded.
+fn parse_cookies(cookie_str: &str) -> impl Iterator<Item = (&str, &str)> {
+    cookie_str
+        .split(';')
+        .map(|cookie| cookie.trim())
+        .filter(|cookie| !cookie.is_empty())
+        .map(|cookie| cookie.split_once('=').unwrap_or(("", cookie)))
+}
+
 /// Parse a cookie string to get the value for a specific key
 fn get_cookie_value(key: &str) -> Option<String> {
     get_cookie_string().and_then(|cookie_str| {
-        cookie_str
-            .split(';')
-            .map(|cookie| cookie.trim())
-            .find(|cookie| cookie.starts_with(&format!("{}=", key)))
-            .and_then(|cookie| cookie.split('=').nth(1))
-            .map(|value| value.to_string())
+        parse_cookies(&cookie_str)
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value.to_string())
     })
 }
 
+/// Get all cookies with their values URL decoded.
+fn get_cookies() -> HashMap<String, String> {
+    get_cookie_string()
+        .map(|cookie_str| {
+            parse_cookies(&cookie_str)
+                .map(|(key, value)| {
+                    let value = urlencoding::decode(value)
+                        .map(|value| value.into_owned())
+                        .unwrap_or_else(|_| value.to_string());
+                    (key.to_string(), value)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Deserialize a raw cookie value set by [`use_cookie`].
+fn decode_cookie_value<T>(value: &str) -> Option<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    // URL decode the value
+    let decoded_value = urlencoding::decode(value).ok()?;
+    serde_json::from_str(&decoded_value).ok()
+}
+
+/// Returns `window.cookieStore` if the Cookie Store API is available.
+fn cookie_store() -> Option<web_sys::EventTarget> {
+    js_sys::Reflect::get(&window(), &JsValue::from_str("cookieStore"))
+        .ok()
+        .filter(|store| store.is_object())
+        .map(|store| store.unchecked_into())
+}
+
+/// Run the `callback` when cookies change, via the Cookie Store API where available,
+/// or by polling `document.cookie` otherwise.
+#[hook]
+fn use_cookie_change<F>(callback: F)
+where
+    F: Fn() + 'static,
+{
+    let callback = use_latest(callback);
+    let has_cookie_store = *use_memo((), |_| cookie_store().is_some());
+
+    {
+        let callback = callback.clone();
+        use_effect_with((), move |_| {
+            let listener = cookie_store().map(|store| {
+                EventListener::new(&store, "change", move |_| (*callback.current())())
+            });
+
+            move || drop(listener)
+        });
+    }
+
+    let last = use_mut_ref(get_cookie_string);
+    use_interval(
+        move || {
+            let current = get_cookie_string();
+            if *last.borrow() != current {
+                *last.borrow_mut() = current;
+                (*callback.current())();
+            }
+        },
+        if has_cookie_store { 0 } else { POLL_INTERVAL },
+    );
+}
+
 /// A side-effect hook that manages a single cookie.
 ///
 /// # Example
@@ -209,14 +404,118 @@ pub fn use_cookie<T>(key: String) -> UseCookieHandle<T>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
+    let raw = use_mut_ref(|| get_cookie_value(&key));
     let inner: UseStateHandle<Option<T>> = use_state(|| {
-        get_cookie_value(&key).and_then(|value| {
-            // URL decode the value
-            let decoded_value = urlencoding::decode(&value).ok()?;
-            serde_json::from_str(&decoded_value).ok()
-        })
+        raw.borrow()
+            .as_deref()
+            .and_then(|value| decode_cookie_value(value))
     });
     let key = use_memo((), |_| key);
 
+    {
+        let key = key.clone();
+        let inner = inner.clone();
+        use_cookie_change(move || {
+            let value = get_cookie_value(&key);
+            if *raw.borrow() != value {
+                inner.set(
+                    value
+                        .as_deref()
+                        .and_then(|value| decode_cookie_value(value)),
+                );
+                *raw.borrow_mut() = value;
+            }
+        });
+    }
+
     UseCookieHandle { inner, key }
 }
+
+/// State handle for the [`use_cookies`] hook.
+pub struct UseCookiesHandle {
+    inner: UseStateHandle<HashMap<String, String>>,
+}
+
+impl UseCookiesHandle {
+    /// Get the value of a cookie set by [`use_cookie`], deserialized from JSON.
+    pub fn get<T>(&self, key: &str) -> Option<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        self.inner
+            .get(key)
+            .and_then(|value| serde_json::from_str(value).ok())
+    }
+
+    /// Read the cookies again, e.g. after they were changed by a server response.
+    pub fn refresh(&self) {
+        let cookies = get_cookies();
+        if *self.inner != cookies {
+            self.inner.set(cookies);
+        }
+    }
+}
+
+impl Deref for UseCookiesHandle {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl Clone for UseCookiesHandle {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl PartialEq for UseCookiesHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+/// A side-effect hook that returns all cookies accessible from script as a map,
+/// with their values URL decoded.
+///
+/// Changes are detected with the Cookie Store API where available,
+/// or by polling `document.cookie` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Cookies)]
+/// fn cookies() -> Html {
+///     let cookies = use_cookies();
+///
+///     html! {
+///         <ul>
+///             {
+///                 for cookies.iter().map(|(key, value)| html! {
+///                     <li><b>{ key }</b>{ ": " }{ value }</li>
+///                 })
+///             }
+///         </ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_cookies() -> UseCookiesHandle {
+    let inner = use_state(get_cookies);
+
+    {
+        let handle = UseCookiesHandle {
+            inner: inner.clone(),
+        };
+        use_cookie_change(move || handle.refresh());
+    }
+
+    UseCookiesHandle { inner }
+}
@@ -1,88 +1,313 @@
 use std::fmt;
-use std::ops::Deref;
+use std::ops::{Add, Deref, Sub};
 use std::rc::Rc;
 
 use yew::prelude::*;
 
-enum CounterAction {
+/// A numeric type that can be used with [`use_counter_with_options`].
+pub trait CounterValue:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + 'static
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The default step.
+    const ONE: Self;
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Addition returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtraction returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_counter_value_int {
+    ($($t:ty),*) => {
+        $(
+            impl CounterValue for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_counter_value_float {
+    ($($t:ty),*) => {
+        $(
+            impl CounterValue for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_counter_value_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_counter_value_float!(f32, f64);
+
+/// What happens when a value goes beyond its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryMode {
+    /// Stop at the bound.
+    #[default]
+    Clamp,
+    /// Continue from the opposite bound.
+    Wrap,
+}
+
+/// Options for [`use_counter_with_options`].
+pub struct UseCounterOptions<T> {
+    /// The smallest value, unbounded if `None`.
+    pub min: Option<T>,
+    /// The largest value, unbounded if `None`.
+    pub max: Option<T>,
+    /// The amount [`increase`](UseCounterHandle::increase) and
+    /// [`decrease`](UseCounterHandle::decrease) change the value by.
+    pub step: T,
+    /// Whether to clamp or wrap around when increasing or decreasing beyond the bounds.
+    /// Wrapping requires both `min` and `max`, otherwise values are clamped.
+    pub mode: BoundaryMode,
+}
+
+impl<T> UseCounterOptions<T>
+where
+    T: CounterValue,
+{
+    /// Keep values between `min` and `max`, inclusive.
+    pub fn with_range(min: T, max: T) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T> Default for UseCounterOptions<T>
+where
+    T: CounterValue,
+{
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: T::ONE,
+            mode: BoundaryMode::default(),
+        }
+    }
+}
+
+enum CounterAction<T> {
     Increase,
-    IncreaseBy(i32),
+    IncreaseBy(T),
     Decrease,
-    DecreaseBy(i32),
-    Set(i32),
+    DecreaseBy(T),
+    Set(T),
     Reset,
+    SetOptions(UseCounterOptions<T>),
+}
+
+struct UseCounterReducer<T> {
+    value: T,
+    default: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    mode: BoundaryMode,
 }
 
-struct UseCounterReducer {
-    value: i32,
-    default: i32,
+impl<T> UseCounterReducer<T>
+where
+    T: CounterValue,
+{
+    /// Add `delta` to the value and bring it back within the bounds.
+    fn add(&self, delta: T) -> T {
+        match self.value.checked_add(delta) {
+            Some(value) => self.bound(value, self.mode),
+            // Overflowing the type is beyond the bound in the direction of `delta`.
+            None if delta < T::ZERO => self.below_min(self.mode),
+            None => self.above_max(self.mode),
+        }
+    }
+
+    /// Subtract `delta` from the value and bring it back within the bounds.
+    fn sub(&self, delta: T) -> T {
+        match self.value.checked_sub(delta) {
+            Some(value) => self.bound(value, self.mode),
+            None if delta < T::ZERO => self.above_max(self.mode),
+            None => self.below_min(self.mode),
+        }
+    }
+
+    /// Bring a value back within the bounds by clamping or wrapping.
+    fn bound(&self, value: T, mode: BoundaryMode) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => self.below_min(mode),
+            (_, Some(max)) if value > max => self.above_max(mode),
+            _ => value,
+        }
+    }
+
+    /// The value replacing one below `min`.
+    fn below_min(&self, mode: BoundaryMode) -> T {
+        match (self.min, self.max) {
+            (Some(_), Some(max)) if mode == BoundaryMode::Wrap => max,
+            (min, _) => min.unwrap_or(T::MIN),
+        }
+    }
+
+    /// The value replacing one above `max`.
+    fn above_max(&self, mode: BoundaryMode) -> T {
+        match (self.min, self.max) {
+            (Some(min), Some(_)) if mode == BoundaryMode::Wrap => min,
+            (_, max) => max.unwrap_or(T::MAX),
+        }
+    }
 }
 
-impl Reducible for UseCounterReducer {
-    type Action = CounterAction;
+impl<T> Reducible for UseCounterReducer<T>
+where
+    T: CounterValue,
+{
+    type Action = CounterAction<T>;
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        if let CounterAction::SetOptions(options) = action {
+            if self.min == options.min
+                && self.max == options.max
+                && self.step == options.step
+                && self.mode == options.mode
+            {
+                return self;
+            }
+            let next = Self {
+                value: self.value,
+                default: self.default,
+                min: options.min,
+                max: options.max,
+                step: options.step,
+                mode: options.mode,
+            };
+            // Keep the value within the new bounds.
+            return Self {
+                value: next.bound(next.value, BoundaryMode::Clamp),
+                ..next
+            }
+            .into();
+        }
+
         let next_value = match action {
-            CounterAction::Increase => self.value + 1,
-            CounterAction::IncreaseBy(delta) => self.value + delta,
-            CounterAction::Decrease => self.value - 1,
-            CounterAction::DecreaseBy(delta) => self.value - delta,
-            CounterAction::Set(value) => value,
-            CounterAction::Reset => self.default,
+            CounterAction::Increase => self.add(self.step),
+            CounterAction::IncreaseBy(delta) => self.add(delta),
+            CounterAction::Decrease => self.sub(self.step),
+            CounterAction::DecreaseBy(delta) => self.sub(delta),
+            CounterAction::Set(value) => self.bound(value, BoundaryMode::Clamp),
+            CounterAction::Reset => self.bound(self.default, BoundaryMode::Clamp),
+            CounterAction::SetOptions(_) => unreachable!(),
         };
 
         Self {
             value: next_value,
             default: self.default,
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            mode: self.mode,
         }
         .into()
     }
 }
 
-impl PartialEq for UseCounterReducer {
+impl<T> PartialEq for UseCounterReducer<T>
+where
+    T: PartialEq,
+{
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
     }
 }
 
-/// State handle for the [`use_counter`] hook.
-pub struct UseCounterHandle {
-    inner: UseReducerHandle<UseCounterReducer>,
+/// State handle for the [`use_counter`] and [`use_counter_with_options`] hooks.
+pub struct UseCounterHandle<T = i32>
+where
+    T: CounterValue,
+{
+    inner: UseReducerHandle<UseCounterReducer<T>>,
 }
 
-impl UseCounterHandle {
-    /// Increase by `1`.
+impl<T> UseCounterHandle<T>
+where
+    T: CounterValue,
+{
+    /// Increase by `1`, or the step if set.
     pub fn increase(&self) {
         self.inner.dispatch(CounterAction::Increase);
     }
 
     /// Increase by `delta`.
-    pub fn increase_by(&self, delta: i32) {
+    pub fn increase_by(&self, delta: T) {
         self.inner.dispatch(CounterAction::IncreaseBy(delta));
     }
 
-    /// Decrease by `1`.
+    /// Decrease by `1`, or the step if set.
     pub fn decrease(&self) {
         self.inner.dispatch(CounterAction::Decrease);
     }
 
     /// Decrease by `delta`.
-    pub fn decrease_by(&self, delta: i32) {
+    pub fn decrease_by(&self, delta: T) {
         self.inner.dispatch(CounterAction::DecreaseBy(delta));
     }
 
-    /// Set to `value`.
-    pub fn set(&self, value: i32) {
+    /// Set to `value`, clamped to the bounds if set.
+    pub fn set(&self, value: T) {
         self.inner.dispatch(CounterAction::Set(value));
     }
 
-    /// Reset to initial value.
+    /// Reset to initial value, clamped to the bounds if set.
     pub fn reset(&self) {
         self.inner.dispatch(CounterAction::Reset);
     }
+
+    /// Returns `true` if the value is at the lower bound.
+    pub fn is_at_min(&self) -> bool {
+        self.inner.min.is_some_and(|min| self.inner.value <= min)
+    }
+
+    /// Returns `true` if the value is at the upper bound.
+    pub fn is_at_max(&self) -> bool {
+        self.inner.max.is_some_and(|max| self.inner.value >= max)
+    }
 }
 
-impl fmt::Debug for UseCounterHandle {
+impl<T> fmt::Debug for UseCounterHandle<T>
+where
+    T: CounterValue + fmt::Debug,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("UseCounterHandle")
             .field("value", &format!("{:?}", self.inner.value))
@@ -91,15 +316,21 @@ impl fmt::Debug for UseCounterHandle {
     }
 }
 
-impl Deref for UseCounterHandle {
-    type Target = i32;
+impl<T> Deref for UseCounterHandle<T>
+where
+    T: CounterValue,
+{
+    type Target = T;
 
     fn deref(&self) -> &Self::Target {
         &self.inner.value
     }
 }
 
-impl Clone for UseCounterHandle {
+impl<T> Clone for UseCounterHandle<T>
+where
+    T: CounterValue,
+{
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -107,7 +338,10 @@ impl Clone for UseCounterHandle {
     }
 }
 
-impl PartialEq for UseCounterHandle {
+impl<T> PartialEq for UseCounterHandle<T>
+where
+    T: CounterValue,
+{
     fn eq(&self, other: &Self) -> bool {
         *self.inner == *other.inner
     }
@@ -169,10 +403,98 @@ impl PartialEq for UseCounterHandle {
 /// ```
 #[hook]
 pub fn use_counter(default: i32) -> UseCounterHandle {
-    let inner = use_reducer(move || UseCounterReducer {
-        value: default,
-        default,
+    use_counter_with_options(default, UseCounterOptions::default())
+}
+
+/// This hook is used to manage counter state of any numeric type with bounds and a step.
+/// See [`use_counter`] too.
+///
+/// Increasing or decreasing beyond the bounds clamps the value, or wraps it around
+/// to the opposite bound with [`BoundaryMode::Wrap`]. The initial value, the value
+/// after a reset and the value when the options change are clamped to the bounds.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Pagination)]
+/// fn pagination() -> Html {
+///     let page = use_counter_with_options(1u32, UseCounterOptions::with_range(1, 10));
+///     let quantity = use_counter_with_options(
+///         0.5f64,
+///         UseCounterOptions {
+///             min: Some(0.5),
+///             max: Some(5.0),
+///             step: 0.5,
+///             mode: BoundaryMode::Clamp,
+///         },
+///     );
+///
+///     let onprev = {
+///         let page = page.clone();
+///         Callback::from(move |_| page.decrease())
+///     };
+///     let onnext = {
+///         let page = page.clone();
+///         Callback::from(move |_| page.increase())
+///     };
+///     let onmore = {
+///         let quantity = quantity.clone();
+///         Callback::from(move |_| quantity.increase())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onprev} disabled={page.is_at_min()}>{ "Previous" }</button>
+///             { *page }
+///             <button onclick={onnext} disabled={page.is_at_max()}>{ "Next" }</button>
+///             <button onclick={onmore} disabled={quantity.is_at_max()}>{ "More" }</button>
+///             { *quantity }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_counter_with_options<T>(default: T, options: UseCounterOptions<T>) -> UseCounterHandle<T>
+where
+    T: CounterValue,
+{
+    let inner = use_reducer(move || {
+        let counter = UseCounterReducer {
+            value: default,
+            default,
+            min: options.min,
+            max: options.max,
+            step: options.step,
+            mode: options.mode,
+        };
+        // Start within the bounds.
+        let default = counter.bound(default, BoundaryMode::Clamp);
+        UseCounterReducer {
+            value: default,
+            default,
+            ..counter
+        }
     });
 
+    {
+        // Follow changes of the options, e.g. a bound that depends on loaded data.
+        let inner = inner.clone();
+        use_effect_with(
+            (options.min, options.max, options.step, options.mode),
+            move |(min, max, step, mode)| {
+                inner.dispatch(CounterAction::SetOptions(UseCounterOptions {
+                    min: *min,
+                    max: *max,
+                    step: *step,
+                    mode: *mode,
+                }));
+            },
+        );
+    }
+
     UseCounterHandle { inner }
 }
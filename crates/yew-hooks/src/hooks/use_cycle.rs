@@ -0,0 +1,258 @@
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::BoundaryMode;
+
+/// Options for [`use_cycle_with_options`].
+pub struct UseCycleOptions {
+    /// Index of the initial value.
+    pub initial_index: usize,
+    /// Whether [`next`](UseCycleHandle::next) and [`prev`](UseCycleHandle::prev)
+    /// wrap around or stop at the ends. Defaults to wrap.
+    pub mode: BoundaryMode,
+}
+
+impl UseCycleOptions {
+    /// Stop at the ends instead of wrapping around.
+    pub const fn clamp() -> Self {
+        Self {
+            initial_index: 0,
+            mode: BoundaryMode::Clamp,
+        }
+    }
+}
+
+impl Default for UseCycleOptions {
+    fn default() -> Self {
+        Self {
+            initial_index: 0,
+            mode: BoundaryMode::Wrap,
+        }
+    }
+}
+
+enum CycleAction {
+    Next,
+    Prev,
+    SetIndex(usize),
+    Reset,
+}
+
+struct UseCycleReducer<T> {
+    values: Rc<Vec<T>>,
+    index: usize,
+    initial_index: usize,
+    mode: BoundaryMode,
+}
+
+impl<T> Reducible for UseCycleReducer<T> {
+    type Action = CycleAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let last = self.values.len() - 1;
+        let wrap = self.mode == BoundaryMode::Wrap;
+        let index = match action {
+            CycleAction::Next if self.index < last => self.index + 1,
+            CycleAction::Next if wrap => 0,
+            CycleAction::Prev if self.index > 0 => self.index - 1,
+            CycleAction::Prev if wrap => last,
+            CycleAction::Next | CycleAction::Prev => self.index,
+            CycleAction::SetIndex(index) => index.min(last),
+            CycleAction::Reset => self.initial_index,
+        };
+
+        Self {
+            values: self.values.clone(),
+            index,
+            initial_index: self.initial_index,
+            mode: self.mode,
+        }
+        .into()
+    }
+}
+
+impl<T> PartialEq for UseCycleReducer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && Rc::ptr_eq(&self.values, &other.values)
+    }
+}
+
+/// State handle for the [`use_cycle`] hook.
+pub struct UseCycleHandle<T> {
+    inner: UseReducerHandle<UseCycleReducer<T>>,
+}
+
+impl<T> UseCycleHandle<T> {
+    /// Move to the next value.
+    pub fn next(&self) {
+        self.inner.dispatch(CycleAction::Next);
+    }
+
+    /// Move to the previous value.
+    pub fn prev(&self) {
+        self.inner.dispatch(CycleAction::Prev);
+    }
+
+    /// Move to the value at `index`, clamped to the last value.
+    pub fn set_index(&self, index: usize) {
+        self.inner.dispatch(CycleAction::SetIndex(index));
+    }
+
+    /// Reset to the initial value.
+    pub fn reset(&self) {
+        self.inner.dispatch(CycleAction::Reset);
+    }
+
+    /// Index of the current value.
+    pub fn index(&self) -> usize {
+        self.inner.index
+    }
+
+    /// All the values cycled through.
+    pub fn values(&self) -> &[T] {
+        &self.inner.values
+    }
+}
+
+impl<T> Deref for UseCycleHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner.values[self.inner.index]
+    }
+}
+
+impl<T> Clone for UseCycleHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseCycleHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UseCycleHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseCycleHandle")
+            .field("value", &format!("{:?}", **self))
+            .field("index", &self.inner.index)
+            .finish()
+    }
+}
+
+/// This hook is used to cycle through more than two values, wrapping around at the ends.
+/// See [`use_toggle`](super::use_toggle) for two values.
+///
+/// Only the `values` of the first render are used, later ones are ignored.
+///
+/// # Panics
+///
+/// Panics if `values` is empty on the first render.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(SortOrder)]
+/// fn sort_order() -> Html {
+///     let order = use_cycle(vec!["None", "Ascending", "Descending"]);
+///
+///     let onclick = {
+///         let order = order.clone();
+///         Callback::from(move |_| order.next())
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "Sort: " }{ *order }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_cycle<T>(values: Vec<T>) -> UseCycleHandle<T>
+where
+    T: 'static,
+{
+    use_cycle_with_options(values, UseCycleOptions::default())
+}
+
+/// This hook is used to cycle through values with options. See [`use_cycle`] too.
+///
+/// Only the `values` of the first render are used, later ones are ignored.
+///
+/// # Panics
+///
+/// Panics if `values` is empty on the first render.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Tabs)]
+/// fn tabs() -> Html {
+///     let tab = use_cycle_with_options(
+///         vec!["Overview", "Details", "Reviews"],
+///         UseCycleOptions {
+///             initial_index: 1,
+///             mode: BoundaryMode::Clamp,
+///         },
+///     );
+///
+///     let onprev = {
+///         let tab = tab.clone();
+///         Callback::from(move |_| tab.prev())
+///     };
+///     let onnext = {
+///         let tab = tab.clone();
+///         Callback::from(move |_| tab.next())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onprev} disabled={tab.index() == 0}>{ "<" }</button>
+///             {
+///                 for tab.values().iter().enumerate().map(|(index, name)| {
+///                     let tab = tab.clone();
+///                     let onclick = Callback::from(move |_| tab.set_index(index));
+///                     html! { <button {onclick}>{ *name }</button> }
+///                 })
+///             }
+///             <button onclick={onnext}>{ ">" }</button>
+///             <p>{ *tab }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_cycle_with_options<T>(values: Vec<T>, options: UseCycleOptions) -> UseCycleHandle<T>
+where
+    T: 'static,
+{
+    let inner = use_reducer(move || {
+        assert!(!values.is_empty(), "use_cycle requires at least one value");
+        let initial_index = options.initial_index.min(values.len() - 1);
+        UseCycleReducer {
+            values: Rc::new(values),
+            index: initial_index,
+            initial_index,
+            mode: options.mode,
+        }
+    });
+
+    UseCycleHandle { inner }
+}
@@ -1,22 +1,282 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use gloo::timers::callback::Timeout;
 use yew::prelude::*;
 
-use super::{use_timeout, UseTimeoutHandle};
+use super::{use_mut_latest, use_unmount, UseMutLatestHandle};
+
+/// Options for [`use_debounce_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseDebounceOptions {
+    /// Invoke on the leading edge of the wait. Defaults to `false`.
+    pub leading: bool,
+    /// Invoke on the trailing edge of the wait. Defaults to `true`.
+    pub trailing: bool,
+    /// Maximum milliseconds the invocation is delayed by repeated runs. Unbounded if `None`.
+    pub max_wait: Option<u32>,
+}
+
+impl UseDebounceOptions {
+    /// Invoke on the leading edge only.
+    pub const fn leading() -> Self {
+        Self {
+            leading: true,
+            trailing: false,
+            max_wait: None,
+        }
+    }
+
+    /// Invoke on the trailing edge, at least every `max_wait` milliseconds.
+    pub const fn with_max_wait(max_wait: u32) -> Self {
+        Self {
+            leading: false,
+            trailing: true,
+            max_wait: Some(max_wait),
+        }
+    }
+}
+
+impl Default for UseDebounceOptions {
+    fn default() -> Self {
+        Self {
+            leading: false,
+            trailing: true,
+            max_wait: None,
+        }
+    }
+}
+
+/// Timing of a [`use_rate_limit`] hook, shared by debounce and throttle hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RateLimit {
+    pub leading: bool,
+    pub trailing: bool,
+    pub max_wait: Option<u32>,
+    /// Restart the wait on every run to debounce, otherwise throttle.
+    pub debounce: bool,
+}
+
+struct RateLimiter<F> {
+    callback: UseMutLatestHandle<F>,
+    millis: Cell<u32>,
+    limit: Cell<RateLimit>,
+    wait: RefCell<Option<Timeout>>,
+    max_wait: RefCell<Option<Timeout>>,
+    /// The timeouts that fired last, kept until they are done running.
+    fired_wait: RefCell<Option<Timeout>>,
+    fired_max_wait: RefCell<Option<Timeout>>,
+    /// Whether a run since the last invocation is waiting for the trailing edge.
+    pending: Cell<bool>,
+}
+
+impl<F> RateLimiter<F>
+where
+    F: FnMut() + 'static,
+{
+    fn invoke(&self) {
+        self.pending.set(false);
+        let callback = self.callback.current();
+        // Runs from within the function itself are not invoked recursively.
+        if let Ok(mut callback) = callback.try_borrow_mut() {
+            (*callback)();
+        };
+    }
+
+    fn start_wait(self: &Rc<Self>) {
+        // Timeouts are owned by the limiter, so they only hold a weak reference back to it.
+        let limiter = Rc::downgrade(self);
+        *self.wait.borrow_mut() = Some(Timeout::new(self.millis.get(), move || {
+            if let Some(limiter) = Weak::upgrade(&limiter) {
+                limiter.on_wait();
+            }
+        }));
+    }
+
+    fn start_max_wait(self: &Rc<Self>, max_wait: u32) {
+        let limiter = Rc::downgrade(self);
+        *self.max_wait.borrow_mut() = Some(Timeout::new(max_wait, move || {
+            if let Some(limiter) = Weak::upgrade(&limiter) {
+                limiter.on_max_wait(max_wait);
+            }
+        }));
+    }
+
+    fn run(self: &Rc<Self>) {
+        let limit = self.limit.get();
+        if self.wait.borrow().is_none() {
+            self.start_wait();
+            if let Some(max_wait) = limit.max_wait {
+                self.start_max_wait(max_wait);
+            }
+            if limit.leading {
+                self.invoke();
+            } else {
+                self.pending.set(true);
+            }
+        } else {
+            self.pending.set(true);
+            if limit.debounce {
+                self.start_wait();
+            }
+        }
+    }
+
+    fn on_wait(self: &Rc<Self>) {
+        let limit = self.limit.get();
+        let fired = self.wait.borrow_mut().take();
+        *self.fired_wait.borrow_mut() = fired;
+        if limit.trailing && self.pending.get() {
+            // A throttle keeps throttling the runs following a trailing invocation.
+            if !limit.debounce {
+                self.start_wait();
+            }
+            self.invoke();
+        } else {
+            self.pending.set(false);
+        }
+        if self.wait.borrow().is_none() {
+            *self.max_wait.borrow_mut() = None;
+        }
+    }
+
+    fn on_max_wait(self: &Rc<Self>, max_wait: u32) {
+        let fired = self.max_wait.borrow_mut().take();
+        *self.fired_max_wait.borrow_mut() = fired;
+        if self.limit.get().trailing && self.pending.get() {
+            self.start_max_wait(max_wait);
+            self.invoke();
+        }
+    }
+
+    fn cancel(&self) {
+        *self.wait.borrow_mut() = None;
+        *self.max_wait.borrow_mut() = None;
+        self.pending.set(false);
+    }
+
+    fn flush(&self) {
+        let pending = self.is_pending();
+        self.cancel();
+        if pending {
+            self.invoke();
+        }
+    }
+
+    fn is_pending(&self) -> bool {
+        self.limit.get().trailing && self.pending.get()
+    }
+}
+
+/// Handle of a [`use_rate_limit`] hook.
+pub(crate) struct UseRateLimitHandle {
+    run: Rc<dyn Fn()>,
+    cancel: Rc<dyn Fn()>,
+    flush: Rc<dyn Fn()>,
+    is_pending: Rc<dyn Fn() -> bool>,
+}
+
+impl UseRateLimitHandle {
+    pub fn run(&self) {
+        (self.run)();
+    }
+
+    pub fn cancel(&self) {
+        (self.cancel)();
+    }
+
+    pub fn flush(&self) {
+        (self.flush)();
+    }
+
+    pub fn is_pending(&self) -> bool {
+        (self.is_pending)()
+    }
+}
+
+impl Clone for UseRateLimitHandle {
+    fn clone(&self) -> Self {
+        Self {
+            run: self.run.clone(),
+            cancel: self.cancel.clone(),
+            flush: self.flush.clone(),
+            is_pending: self.is_pending.clone(),
+        }
+    }
+}
+
+/// Debounce or throttle invoking `callback`, the timer logic shared by debounce and throttle hooks.
+/// Pending invocations are cancelled on unmount.
+#[hook]
+pub(crate) fn use_rate_limit<F>(callback: F, millis: u32, limit: RateLimit) -> UseRateLimitHandle
+where
+    F: FnMut() + 'static,
+{
+    let callback = use_mut_latest(callback);
+    let limiter = use_mut_ref(|| {
+        Rc::new(RateLimiter {
+            callback,
+            millis: Cell::new(millis),
+            limit: Cell::new(limit),
+            wait: RefCell::new(None),
+            max_wait: RefCell::new(None),
+            fired_wait: RefCell::new(None),
+            fired_max_wait: RefCell::new(None),
+            pending: Cell::new(false),
+        })
+    });
+    let limiter = limiter.borrow().clone();
+    limiter.millis.set(millis);
+    limiter.limit.set(limit);
+
+    {
+        let limiter = limiter.clone();
+        use_unmount(move || limiter.cancel());
+    }
+
+    UseRateLimitHandle {
+        run: {
+            let limiter = limiter.clone();
+            Rc::new(move || limiter.run())
+        },
+        cancel: {
+            let limiter = limiter.clone();
+            Rc::new(move || limiter.cancel())
+        },
+        flush: {
+            let limiter = limiter.clone();
+            Rc::new(move || limiter.flush())
+        },
+        is_pending: Rc::new(move || limiter.is_pending()),
+    }
+}
 
 /// State handle for the [`use_debounce`] hook.
 pub struct UseDebounceHandle {
-    inner: UseTimeoutHandle,
+    inner: UseRateLimitHandle,
 }
 
 impl UseDebounceHandle {
     /// Run the debounce.
     pub fn run(&self) {
-        self.inner.reset();
+        self.inner.run();
     }
 
     /// Cancel the debounce.
     pub fn cancel(&self) {
         self.inner.cancel();
     }
+
+    /// Invoke the pending function immediately, if any.
+    pub fn flush(&self) {
+        self.inner.flush();
+    }
+
+    /// Returns `true` if an invocation is waiting for the wait to elapse.
+    /// Changes do not re-render the component by themselves.
+    pub fn is_pending(&self) -> bool {
+        self.inner.is_pending()
+    }
 }
 
 impl Clone for UseDebounceHandle {
@@ -30,6 +290,9 @@ impl Clone for UseDebounceHandle {
 /// A hook that delays invoking a function until after wait milliseconds have elapsed
 /// since the last time the debounced function was invoked.
 ///
+/// The wait also starts on mount and restarts when `millis` changes, a `millis` of `0` cancels it.
+/// See [`use_debounce_with_options`] to only wait after [`run`](UseDebounceHandle::run).
+///
 /// # Example
 ///
 /// ```rust
@@ -91,7 +354,101 @@ pub fn use_debounce<Callback>(callback: Callback, millis: u32) -> UseDebounceHan
 where
     Callback: FnOnce() + 'static,
 {
-    let inner = use_timeout(callback, millis);
+    // The function is only invoked once per render, as it was given by value.
+    let mut callback = Some(callback);
+    let debounce = use_debounce_with_options(
+        move || {
+            if let Some(callback) = callback.take() {
+                callback();
+            }
+        },
+        millis,
+        UseDebounceOptions::default(),
+    );
+
+    {
+        let debounce = debounce.clone();
+        use_effect_with(millis, move |millis| {
+            if *millis > 0 {
+                debounce.run();
+            } else {
+                debounce.cancel();
+            }
+            || ()
+        });
+    }
+
+    debounce
+}
+
+/// A hook that debounces invoking a function with options, e.g. on the leading edge
+/// or at least every `max_wait` milliseconds. See [`use_debounce`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_sys::HtmlInputElement;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(AutoSave)]
+/// fn auto_save() -> Html {
+///     let text = use_state(String::new);
+///     let saved = use_state(String::new);
+///
+///     // Save when typing stops for 1 second, and at least every 5 seconds while typing.
+///     let save = {
+///         let text = text.clone();
+///         let saved = saved.clone();
+///         use_debounce_with_options(
+///             move || saved.set((*text).clone()),
+///             1000,
+///             UseDebounceOptions::with_max_wait(5000),
+///         )
+///     };
+///
+///     let oninput = {
+///         let save = save.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let input: HtmlInputElement = e.target_unchecked_into();
+///             text.set(input.value());
+///             save.run();
+///         })
+///     };
+///     let onsave = {
+///         let save = save.clone();
+///         Callback::from(move |_| save.flush())
+///     };
+///
+///     html! {
+///         <>
+///             <textarea {oninput} />
+///             <button onclick={onsave} disabled={!save.is_pending()}>{ "Save now" }</button>
+///             <b>{ "Saved: " }</b> { &*saved }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounce_with_options<Callback>(
+    callback: Callback,
+    millis: u32,
+    options: UseDebounceOptions,
+) -> UseDebounceHandle
+where
+    Callback: FnMut() + 'static,
+{
+    let inner = use_rate_limit(
+        callback,
+        millis,
+        RateLimit {
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: options.max_wait,
+            debounce: true,
+        },
+    );
 
     UseDebounceHandle { inner }
 }
@@ -3,7 +3,7 @@ use std::rc::Rc;
 
 use yew::prelude::*;
 
-use super::use_debounce;
+use super::{use_debounce_with_options, UseDebounceOptions};
 
 /// State handle for the [`use_debounce_state`] hook.
 pub struct UseDebounceStateHandle<T> {
@@ -77,6 +77,46 @@ where
 /// ```
 #[hook]
 pub fn use_debounce_state<T, F>(init_fn: F, millis: u32) -> UseDebounceStateHandle<T>
+where
+    T: 'static,
+    F: FnOnce() -> T,
+{
+    use_debounce_state_with_options(init_fn, millis, UseDebounceOptions::default())
+}
+
+/// A hook that debounces updating state with [`UseDebounceOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(DebounceState)]
+/// fn debounce_state() -> Html {
+///     // Updates at least once a second while clicking.
+///     let state = use_debounce_state_with_options(|| 0, 300, UseDebounceOptions::with_max_wait(1000));
+///
+///     let onclick = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(*state + 1))
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Click fast!" }</button>
+///             <b>{ "State: " }</b> {*state}
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounce_state_with_options<T, F>(
+    init_fn: F,
+    millis: u32,
+    options: UseDebounceOptions,
+) -> UseDebounceStateHandle<T>
 where
     T: 'static,
     F: FnOnce() -> T,
@@ -86,7 +126,7 @@ where
     let debounce = {
         let value = value.clone();
         let inner = inner.clone();
-        use_debounce(
+        use_debounce_with_options(
             move || {
                 let value = (*value.borrow_mut()).take();
                 if let Some(value) = value {
@@ -94,6 +134,7 @@ where
                 }
             },
             millis,
+            options,
         )
     };
 
@@ -0,0 +1,132 @@
+use yew::prelude::*;
+
+use super::{
+    use_effect_update_with_deps, use_latest, use_rate_limit, RateLimit, UseDebounceOptions,
+};
+
+/// A hook that returns a copy of `value` which only updates after `millis` milliseconds
+/// have elapsed since `value` last changed.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_sys::HtmlInputElement;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///     query: String,
+/// }
+///
+/// #[function_component(SearchResults)]
+/// fn search_results(props: &Props) -> Html {
+///     // Only search once typing stops.
+///     let query = use_debounced_value(props.query.clone(), 500);
+///
+///     html! {
+///         <p>{ "Searching for " }{ query }</p>
+///     }
+/// }
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///     let query = use_state(String::new);
+///
+///     let oninput = {
+///         let query = query.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let input: HtmlInputElement = e.target_unchecked_into();
+///             query.set(input.value());
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <input type="text" placeholder="Search" {oninput}/>
+///             <SearchResults query={(*query).clone()} />
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounced_value<T>(value: T, millis: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    use_debounced_value_with_options(value, millis, UseDebounceOptions::default())
+}
+
+/// A hook that returns a debounced copy of `value` with [`UseDebounceOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(DebouncedValue)]
+/// fn debounced_value() -> Html {
+///     let counter = use_counter(0);
+///     // Follows the counter at least once a second while clicking.
+///     let debounced = use_debounced_value_with_options(*counter, 300, UseDebounceOptions::with_max_wait(1000));
+///
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.increase())
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Click fast!" }</button>
+///             <b>{ "Debounced: " }</b> { debounced }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounced_value_with_options<T>(value: T, millis: u32, options: UseDebounceOptions) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    use_rate_limited_value(
+        value,
+        millis,
+        RateLimit {
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: options.max_wait,
+            debounce: true,
+        },
+    )
+}
+
+/// Follow `value` with [`use_rate_limit`], shared by debounced and throttled values.
+#[hook]
+pub(crate) fn use_rate_limited_value<T>(value: T, millis: u32, limit: RateLimit) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let state = use_state_eq(|| value.clone());
+    let latest = use_latest(value.clone());
+    let limiter = {
+        let state = state.clone();
+        use_rate_limit(
+            move || state.set((*latest.current()).clone()),
+            millis,
+            limit,
+        )
+    };
+
+    use_effect_update_with_deps(
+        move |_| {
+            limiter.run();
+            || ()
+        },
+        value,
+    );
+
+    (*state).clone()
+}
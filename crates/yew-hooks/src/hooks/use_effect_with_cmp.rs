@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+/// This hook is similar to [`use_effect_with`] but compares dependencies with `eq`
+/// instead of `PartialEq`. The callback runs on mount and whenever `eq` returns `false`
+/// for the previous and new dependencies. Useful to depend on large structs by a version field,
+/// or on `Rc`s by pointer with [`Rc::ptr_eq`].
+///
+/// # Example
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # use yew::prelude::*;
+/// # use log::debug;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// struct Document {
+///     version: u32,
+///     text: String,
+/// }
+///
+/// #[derive(Properties)]
+/// struct Props {
+///     document: Rc<Document>,
+/// }
+///
+/// impl PartialEq for Props {
+///     fn eq(&self, other: &Self) -> bool {
+///         Rc::ptr_eq(&self.document, &other.document)
+///     }
+/// }
+///
+/// #[function_component(UseEffectWithCmp)]
+/// fn effect_with_cmp(props: &Props) -> Html {
+///     use_effect_with_cmp(
+///         props.document.clone(),
+///         |a, b| a.version == b.version,
+///         |document| {
+///             debug!("Saving version {}", document.version);
+///         },
+///     );
+///
+///     html! {
+///         <p>{ &props.document.text }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_effect_with_cmp<Callback, Destructor, Dependents, Eq>(
+    deps: Dependents,
+    eq: Eq,
+    callback: Callback,
+) where
+    Callback: FnOnce(&Dependents) -> Destructor + 'static,
+    Destructor: TearDown,
+    Dependents: 'static,
+    Eq: Fn(&Dependents, &Dependents) -> bool,
+{
+    let (version, deps) = use_cmp_version(deps, eq);
+
+    use_effect_with(version, move |_| callback(&deps));
+}
+
+/// Keep the last dependencies that `eq` considered changed, along with a version
+/// that increases on every change.
+#[hook]
+pub(crate) fn use_cmp_version<Dependents, Eq>(deps: Dependents, eq: Eq) -> (usize, Rc<Dependents>)
+where
+    Dependents: 'static,
+    Eq: Fn(&Dependents, &Dependents) -> bool,
+{
+    let last = use_mut_ref(|| None::<(usize, Rc<Dependents>)>);
+    let mut last = last.borrow_mut();
+
+    match &*last {
+        Some((version, prev)) if eq(prev, &deps) => (*version, prev.clone()),
+        _ => {
+            let version = last
+                .as_ref()
+                .map_or(0, |(version, _)| version.wrapping_add(1));
+            let deps = Rc::new(deps);
+            *last = Some((version, deps.clone()));
+            (version, deps)
+        }
+    }
+}
@@ -0,0 +1,500 @@
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use gloo::utils::window;
+use js_sys::{Array, Uint8Array};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{AesDerivedKeyParams, AesGcmParams, Pbkdf2Params, StorageEvent, SubtleCrypto};
+use yew::prelude::*;
+
+use super::{
+    read_storage, use_event_with_window, write_storage, LocalStorageBackend, StorageBackend,
+    StorageWriteError,
+};
+
+pub use web_sys::CryptoKey;
+
+/// Length of the random initialization vector of AES-GCM, in bytes.
+const IV_LENGTH: usize = 12;
+
+/// Key used by [`use_encrypted_storage`] to encrypt values with AES-GCM.
+#[derive(Clone, PartialEq)]
+pub enum EncryptionKey {
+    /// A raw 128, 192 or 256-bit AES key.
+    Raw(Vec<u8>),
+    /// An AES-GCM `CryptoKey` with `encrypt` and `decrypt` usages.
+    CryptoKey(CryptoKey),
+    /// A 256-bit key derived from a passphrase with PBKDF2-SHA-256.
+    Passphrase {
+        passphrase: String,
+        salt: Vec<u8>,
+        iterations: u32,
+    },
+}
+
+/// Error for the [`use_encrypted_storage`] hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncryptedStorageError {
+    /// Web Crypto is not available, e.g. the page is not served in a secure context.
+    Unavailable,
+    /// The key could not be imported or derived.
+    Key(String),
+    /// The value could not be encrypted.
+    Encrypt(String),
+    /// The stored value could not be decrypted, the key is wrong or the value was tampered with.
+    Decrypt,
+    /// The value could not be serialized or deserialized.
+    Serde(String),
+    /// The encrypted value could not be written to storage.
+    Storage(StorageWriteError),
+}
+
+impl fmt::Display for EncryptedStorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "Web Crypto is not available"),
+            Self::Key(message) => write!(f, "failed to import key: {}", message),
+            Self::Encrypt(message) => write!(f, "failed to encrypt value: {}", message),
+            Self::Decrypt => write!(f, "failed to decrypt value"),
+            Self::Serde(message) => write!(f, "failed to (de)serialize value: {}", message),
+            Self::Storage(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for EncryptedStorageError {}
+
+/// Encrypted value as stored in localStorage.
+#[derive(Serialize, Deserialize)]
+struct Encrypted {
+    /// Base64 encoded initialization vector.
+    iv: String,
+    /// Base64 encoded ciphertext.
+    data: String,
+}
+
+/// State handle for the [`use_encrypted_storage`] hook.
+pub struct UseEncryptedStorageHandle<T> {
+    inner: UseStateHandle<Option<T>>,
+    /// If the key is being imported or the stored value is being decrypted.
+    pub loading: UseStateHandle<bool>,
+    /// The error of the last failed operation.
+    pub error: UseStateHandle<Option<EncryptedStorageError>>,
+    crypto_key: UseStateHandle<Option<CryptoKey>>,
+    key: Rc<String>,
+}
+
+impl<T> UseEncryptedStorageHandle<T> {
+    /// Encrypt and set a `value` for the specified key.
+    /// The state is updated once the value is encrypted and written.
+    pub fn set(&self, value: T)
+    where
+        T: Serialize + 'static,
+    {
+        let inner = self.inner.clone();
+        let error = self.error.clone();
+        let crypto_key = (*self.crypto_key).clone();
+        let key = self.key.clone();
+        spawn_local(async move {
+            let result = match crypto_key {
+                Some(crypto_key) => encrypt_to_storage(&crypto_key, &key, &value).await,
+                None => Err(EncryptedStorageError::Key(
+                    "key has not been imported".to_string(),
+                )),
+            };
+            match result {
+                Ok(()) => {
+                    inner.set(Some(value));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    }
+
+    /// Delete a key and its stored value.
+    pub fn delete(&self) {
+        LocalStorageBackend.remove_item(&self.key);
+        self.inner.set(None);
+    }
+}
+
+impl<T> Deref for UseEncryptedStorageHandle<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseEncryptedStorageHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            loading: self.loading.clone(),
+            error: self.error.clone(),
+            crypto_key: self.crypto_key.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseEncryptedStorageHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+            && *self.loading == *other.loading
+            && *self.error == *other.error
+    }
+}
+
+fn subtle() -> Result<SubtleCrypto, EncryptedStorageError> {
+    let subtle = window()
+        .crypto()
+        .map_err(|_| EncryptedStorageError::Unavailable)?
+        .subtle();
+    // `crypto.subtle` is undefined in insecure contexts.
+    if subtle.is_undefined() {
+        Err(EncryptedStorageError::Unavailable)
+    } else {
+        Ok(subtle)
+    }
+}
+
+fn js_message(error: &JsValue) -> String {
+    js_sys::Reflect::get(error, &JsValue::from_str("message"))
+        .ok()
+        .and_then(|v| v.as_string())
+        .unwrap_or_default()
+}
+
+fn key_usages(usages: &[&str]) -> Array {
+    usages
+        .iter()
+        .map(|usage| JsValue::from_str(usage))
+        .collect()
+}
+
+/// Base64 encode `bytes` with `btoa`.
+fn encode_base64(bytes: &[u8]) -> String {
+    let binary: String = bytes.iter().map(|&b| char::from(b)).collect();
+    window().btoa(&binary).unwrap_or_default()
+}
+
+/// Base64 decode `value` with `atob`.
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    let binary = window().atob(value).ok()?;
+    binary.chars().map(|c| u8::try_from(c).ok()).collect()
+}
+
+/// Import or derive an AES-GCM `CryptoKey`.
+async fn import_key(encryption_key: &EncryptionKey) -> Result<CryptoKey, EncryptedStorageError> {
+    let subtle = subtle()?;
+    let key_error = |e: JsValue| EncryptedStorageError::Key(js_message(&e));
+    let promise = match encryption_key {
+        EncryptionKey::CryptoKey(crypto_key) => return Ok(crypto_key.clone()),
+        EncryptionKey::Raw(bytes) => subtle
+            .import_key_with_str(
+                "raw",
+                &Uint8Array::from(bytes.as_slice()),
+                "AES-GCM",
+                false,
+                &key_usages(&["encrypt", "decrypt"]),
+            )
+            .map_err(key_error)?,
+        EncryptionKey::Passphrase {
+            passphrase,
+            salt,
+            iterations,
+        } => {
+            let base_key = subtle
+                .import_key_with_str(
+                    "raw",
+                    &Uint8Array::from(passphrase.as_bytes()),
+                    "PBKDF2",
+                    false,
+                    &key_usages(&["deriveKey"]),
+                )
+                .map_err(key_error)?;
+            let base_key: CryptoKey = JsFuture::from(base_key)
+                .await
+                .map_err(key_error)?
+                .unchecked_into();
+            let params = Pbkdf2Params::new_with_str(
+                "PBKDF2",
+                "SHA-256",
+                *iterations,
+                &Uint8Array::from(salt.as_slice()),
+            );
+            subtle
+                .derive_key_with_object_and_object(
+                    &params,
+                    &base_key,
+                    &AesDerivedKeyParams::new("AES-GCM", 256),
+                    false,
+                    &key_usages(&["encrypt", "decrypt"]),
+                )
+                .map_err(key_error)?
+        }
+    };
+    JsFuture::from(promise)
+        .await
+        .map(JsCast::unchecked_into)
+        .map_err(key_error)
+}
+
+/// AES-GCM parameters authenticating the storage `key` along with the value,
+/// so a value copied to another key fails to decrypt.
+fn aes_gcm_params(iv: &[u8], key: &str) -> AesGcmParams {
+    let params = AesGcmParams::new_with_u8_array("AES-GCM", &Uint8Array::from(iv));
+    params.set_additional_data_u8_array(&Uint8Array::from(key.as_bytes()));
+    params
+}
+
+/// Serialize, encrypt and write a `value` to localStorage.
+async fn encrypt_to_storage<T>(
+    crypto_key: &CryptoKey,
+    key: &str,
+    value: &T,
+) -> Result<(), EncryptedStorageError>
+where
+    T: Serialize,
+{
+    let subtle = subtle()?;
+    let plaintext =
+        serde_json::to_vec(value).map_err(|e| EncryptedStorageError::Serde(e.to_string()))?;
+
+    let mut iv = [0u8; IV_LENGTH];
+    window()
+        .crypto()
+        .and_then(|crypto| crypto.get_random_values_with_u8_array(&mut iv))
+        .map_err(|e| EncryptedStorageError::Encrypt(js_message(&e)))?;
+
+    let params = aes_gcm_params(&iv, key);
+    let promise = subtle
+        .encrypt_with_object_and_u8_array(&params, crypto_key, &plaintext)
+        .map_err(|e| EncryptedStorageError::Encrypt(js_message(&e)))?;
+    let ciphertext = JsFuture::from(promise)
+        .await
+        .map_err(|e| EncryptedStorageError::Encrypt(js_message(&e)))?;
+
+    let encrypted = Encrypted {
+        iv: encode_base64(&iv),
+        data: encode_base64(&Uint8Array::new(&ciphertext).to_vec()),
+    };
+    write_storage(&LocalStorageBackend, key, &encrypted, None)
+        .map_err(EncryptedStorageError::Storage)
+}
+
+/// Read and decrypt a value from localStorage.
+async fn decrypt_from_storage<T>(
+    crypto_key: &CryptoKey,
+    key: &str,
+) -> Result<Option<T>, EncryptedStorageError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let encrypted = match read_storage::<_, Encrypted>(&LocalStorageBackend, key).value {
+        Some(encrypted) => encrypted,
+        None => return Ok(None),
+    };
+    let (iv, data) = match (decode_base64(&encrypted.iv), decode_base64(&encrypted.data)) {
+        (Some(iv), Some(data)) => (iv, data),
+        _ => return Err(EncryptedStorageError::Decrypt),
+    };
+
+    let subtle = subtle()?;
+    let params = aes_gcm_params(&iv, key);
+    let promise = subtle
+        .decrypt_with_object_and_u8_array(&params, crypto_key, &data)
+        .map_err(|_| EncryptedStorageError::Decrypt)?;
+    let plaintext = JsFuture::from(promise)
+        .await
+        .map_err(|_| EncryptedStorageError::Decrypt)?;
+
+    serde_json::from_slice(&Uint8Array::new(&plaintext).to_vec())
+        .map(Some)
+        .map_err(|e| EncryptedStorageError::Serde(e.to_string()))
+}
+
+/// A side-effect hook that manages a single localStorage key,
+/// encrypting the serialized value with AES-GCM using the Web Crypto API.
+///
+/// The key is imported (or derived from a passphrase with PBKDF2) and the stored value
+/// is decrypted asynchronously, `loading` is `true` until then.
+/// A value that cannot be decrypted is read as absent and reported in `error`.
+/// The storage key is authenticated with the value, so a value moved to another key
+/// cannot be decrypted either.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(EncryptedStorage)]
+/// fn encrypted_storage() -> Html {
+///     let storage = use_encrypted_storage::<String>(
+///         "token".to_string(),
+///         EncryptionKey::Passphrase {
+///             passphrase: "correct horse battery staple".to_string(),
+///             salt: b"yew-hooks".to_vec(),
+///             iterations: 100_000,
+///         },
+///     );
+///
+///     let onclick = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.set("secret".to_string()))
+///     };
+///     let ondelete = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.delete())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick} disabled={*storage.loading}>{ "Set to secret" }</button>
+///             <button onclick={ondelete}>{ "Delete" }</button>
+///             <p>
+///                 <b>{ "Current value: " }</b>
+///                 {
+///                     if let Some(value) = &*storage {
+///                         html! { value }
+///                     } else {
+///                         html! {}
+///                     }
+///                 }
+///             </p>
+///             {
+///                 if let Some(error) = &*storage.error {
+///                     html! { <p>{ error.to_string() }</p> }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_encrypted_storage<T>(
+    key: String,
+    encryption_key: EncryptionKey,
+) -> UseEncryptedStorageHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    let inner: UseStateHandle<Option<T>> = use_state(|| None);
+    let loading = use_state(|| true);
+    let error = use_state(|| None);
+    let crypto_key: UseStateHandle<Option<CryptoKey>> = use_state(|| None);
+    let key = use_memo((), |_| key);
+    // Bumped by every load and key change, so only the latest decrypt is applied.
+    let generation = use_mut_ref(|| 0u32);
+
+    let load = {
+        let inner = inner.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let key = key.clone();
+        let generation = generation.clone();
+        Rc::new(move |crypto_key: CryptoKey| {
+            let inner = inner.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let key = key.clone();
+            let generation = generation.clone();
+            let started = {
+                let mut generation = generation.borrow_mut();
+                *generation = generation.wrapping_add(1);
+                *generation
+            };
+            spawn_local(async move {
+                let result = decrypt_from_storage(&crypto_key, &key).await;
+                if *generation.borrow() != started {
+                    return;
+                }
+                match result {
+                    Ok(value) => {
+                        inner.set(value);
+                        error.set(None);
+                    }
+                    Err(e) => {
+                        inner.set(None);
+                        error.set(Some(e));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let loading = loading.clone();
+        let error = error.clone();
+        let crypto_key = crypto_key.clone();
+        let load = load.clone();
+        use_effect_with(encryption_key, move |encryption_key| {
+            let encryption_key = encryption_key.clone();
+            let cancelled = Rc::new(Cell::new(false));
+            // Drop decrypts with the previous key still running.
+            {
+                let mut generation = generation.borrow_mut();
+                *generation = generation.wrapping_add(1);
+            }
+            if !*loading {
+                loading.set(true);
+            }
+            {
+                let cancelled = cancelled.clone();
+                spawn_local(async move {
+                    let result = import_key(&encryption_key).await;
+                    // Ignore keys that have been replaced in the meantime.
+                    if cancelled.get() {
+                        return;
+                    }
+                    match result {
+                        Ok(imported) => {
+                            crypto_key.set(Some(imported.clone()));
+                            load(imported);
+                        }
+                        Err(e) => {
+                            crypto_key.set(None);
+                            error.set(Some(e));
+                            loading.set(false);
+                        }
+                    }
+                });
+            }
+            move || cancelled.set(true)
+        });
+    }
+
+    {
+        let key = key.clone();
+        let crypto_key = crypto_key.clone();
+        use_event_with_window("storage", move |e: StorageEvent| {
+            if let (Some(k), Some(crypto_key)) = (e.key(), (*crypto_key).clone()) {
+                if k == *key {
+                    load(crypto_key);
+                }
+            }
+        });
+    }
+
+    UseEncryptedStorageHandle {
+        inner,
+        loading,
+        error,
+        crypto_key,
+        key,
+    }
+}
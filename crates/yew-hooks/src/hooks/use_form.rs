@@ -0,0 +1,799 @@
+use std::cell::{Ref, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use super::{use_latest, use_update};
+
+type SyncValidator<T> = Box<dyn Fn(&str, &T) -> Result<(), String>>;
+type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+type AsyncValidator<T> = Box<dyn Fn(String, T) -> ValidationFuture>;
+
+/// Validation of the fields of a [`use_form_with_options`] hook.
+///
+/// Validators of a field array apply to each of its items.
+pub struct UseFormOptions<T> {
+    validators: Vec<(String, SyncValidator<T>)>,
+    async_validators: Vec<(String, AsyncValidator<T>)>,
+}
+
+impl<T> UseFormOptions<T>
+where
+    T: 'static,
+{
+    /// Options without validation.
+    pub fn new() -> Self {
+        Self {
+            validators: vec![],
+            async_validators: vec![],
+        }
+    }
+
+    /// Add a validator of the field `name`, called with the field value and all the form values.
+    pub fn validator<F>(mut self, name: &str, validator: F) -> Self
+    where
+        F: Fn(&str, &T) -> Result<(), String> + 'static,
+    {
+        self.validators
+            .push((name.to_string(), Box::new(validator)));
+        self
+    }
+
+    /// Add an async validator of the field `name`, e.g. checking a username is available.
+    /// Only runs once the synchronous validators of the field pass.
+    pub fn async_validator<F, Fut>(mut self, name: &str, validator: F) -> Self
+    where
+        F: Fn(String, T) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.async_validators.push((
+            name.to_string(),
+            Box::new(move |value, values| Box::pin(validator(value, values))),
+        ));
+        self
+    }
+}
+
+impl<T> Default for UseFormOptions<T>
+where
+    T: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a registered field reads its value from the form values.
+enum Accessor<T> {
+    Field(fn(&mut T) -> &mut String),
+    Item(fn(&mut T) -> &mut Vec<String>, usize),
+}
+
+impl<T> Accessor<T> {
+    fn get<'a>(&self, values: &'a mut T) -> Option<&'a mut String> {
+        match self {
+            Self::Field(field) => Some(field(values)),
+            Self::Item(array, index) => array(values).get_mut(*index),
+        }
+    }
+}
+
+struct RegisteredField<T> {
+    accessor: Accessor<T>,
+    /// Name of the validators of the field, the array name for array items.
+    rules: String,
+}
+
+struct FormState<T> {
+    values: T,
+    initial: T,
+    fields: BTreeMap<String, RegisteredField<T>>,
+    errors: HashMap<String, String>,
+    touched: HashSet<String>,
+    validating: HashSet<String>,
+    /// The latest async validation of each field, so stale results are ignored.
+    validations: HashMap<String, u64>,
+    next_validation: u64,
+    submitting: bool,
+    submit_count: usize,
+}
+
+impl<T> FormState<T> {
+    fn value(&mut self, name: &str) -> Option<&mut String> {
+        let field = self.fields.get(name)?;
+        field.accessor.get(&mut self.values)
+    }
+
+    fn initial_value(&mut self, name: &str) -> Option<&mut String> {
+        let field = self.fields.get(name)?;
+        field.accessor.get(&mut self.initial)
+    }
+
+    fn set_error(&mut self, name: &str, error: Option<String>) {
+        match error {
+            Some(error) => self.errors.insert(name.to_string(), error),
+            None => self.errors.remove(name),
+        };
+    }
+}
+
+struct FormInner<T> {
+    state: RefCell<FormState<T>>,
+    options: UseFormOptions<T>,
+}
+
+impl<T> FormInner<T>
+where
+    T: Clone + 'static,
+{
+    /// The error of the synchronous validators of a field.
+    fn sync_error(&self, name: &str) -> Option<String> {
+        let mut state = self.state.borrow_mut();
+        let value = state.value(name)?.clone();
+        let rules = &state.fields.get(name)?.rules;
+        self.options
+            .validators
+            .iter()
+            .filter(|(key, _)| key == rules)
+            .find_map(|(_, validator)| validator(&value, &state.values).err())
+    }
+
+    /// A future resolving to the error of the async validators of a field, if it has any.
+    fn async_validation(
+        &self,
+        name: &str,
+    ) -> Option<Pin<Box<dyn Future<Output = Option<String>>>>> {
+        let mut state = self.state.borrow_mut();
+        let value = state.value(name)?.clone();
+        let rules = &state.fields.get(name)?.rules;
+        let validations = self
+            .options
+            .async_validators
+            .iter()
+            .filter(|(key, _)| key == rules)
+            .map(|(_, validator)| validator(value.clone(), state.values.clone()))
+            .collect::<Vec<_>>();
+        if validations.is_empty() {
+            return None;
+        }
+
+        Some(Box::pin(async move {
+            for validation in validations {
+                if let Err(error) = validation.await {
+                    return Some(error);
+                }
+            }
+            None
+        }))
+    }
+
+    /// Run the validators of a field, async ones in the background.
+    fn validate_field(self: &Rc<Self>, name: &str, update: Rc<dyn Fn()>) {
+        let error = self.sync_error(name);
+        let valid = error.is_none();
+        self.state.borrow_mut().set_error(name, error);
+        if !valid {
+            return;
+        }
+
+        if let Some(validation) = self.async_validation(name) {
+            let id = {
+                let mut state = self.state.borrow_mut();
+                let id = state.next_validation;
+                state.next_validation += 1;
+                state.validations.insert(name.to_string(), id);
+                state.validating.insert(name.to_string());
+                id
+            };
+            let inner = self.clone();
+            let name = name.to_string();
+            spawn_local(async move {
+                let error = validation.await;
+                {
+                    let mut state = inner.state.borrow_mut();
+                    if state.validations.get(&name) != Some(&id) {
+                        return;
+                    }
+                    state.validations.remove(&name);
+                    state.validating.remove(&name);
+                    state.set_error(&name, error);
+                }
+                update();
+            });
+        }
+    }
+
+    fn set_value(self: &Rc<Self>, name: &str, value: String, update: Rc<dyn Fn()>) {
+        let validate = {
+            let mut state = self.state.borrow_mut();
+            match state.value(name) {
+                Some(current) => *current = value,
+                None => return,
+            }
+            state.touched.contains(name) || state.submit_count > 0
+        };
+        if validate {
+            self.validate_field(name, update.clone());
+        }
+        update();
+    }
+
+    fn blur(self: &Rc<Self>, name: &str, update: Rc<dyn Fn()>) {
+        self.state.borrow_mut().touched.insert(name.to_string());
+        self.validate_field(name, update.clone());
+        update();
+    }
+
+    fn register(&self, name: &str, accessor: Accessor<T>, rules: &str) {
+        self.state.borrow_mut().fields.insert(
+            name.to_string(),
+            RegisteredField {
+                accessor,
+                rules: rules.to_string(),
+            },
+        );
+    }
+
+    fn field(self: &Rc<Self>, name: &str, update: Rc<dyn Fn()>) -> FormField {
+        let mut state = self.state.borrow_mut();
+        let value = state.value(name).cloned().unwrap_or_default();
+        let dirty = state
+            .initial_value(name)
+            .is_none_or(|initial| *initial != value);
+
+        let oninput = {
+            let inner = self.clone();
+            let name = name.to_string();
+            let update = update.clone();
+            Callback::from(move |e: InputEvent| {
+                if let Some(value) = event_value(&e) {
+                    inner.set_value(&name, value, update.clone());
+                }
+            })
+        };
+        let onblur = {
+            let inner = self.clone();
+            let name = name.to_string();
+            Callback::from(move |_: FocusEvent| inner.blur(&name, update.clone()))
+        };
+
+        FormField {
+            name: name.to_string(),
+            value,
+            oninput,
+            onblur,
+            error: state.errors.get(name).cloned(),
+            dirty,
+            touched: state.touched.contains(name),
+            validating: state.validating.contains(name),
+        }
+    }
+}
+
+/// Read the value of the element an event was dispatched to,
+/// e.g. an input, a textarea or a select.
+fn event_value(e: &Event) -> Option<String> {
+    let target = e.target()?;
+    js_sys::Reflect::get(&target, &JsValue::from_str("value"))
+        .ok()?
+        .as_string()
+}
+
+/// Rename `array.{index}` keys after an item was removed from `array`.
+fn shift_keys<V>(map: HashMap<String, V>, array: &str, removed: usize) -> HashMap<String, V> {
+    map.into_iter()
+        .filter_map(|(key, value)| match item_index(&key, array) {
+            Some(index) if index == removed => None,
+            Some(index) if index > removed => Some((format!("{}.{}", array, index - 1), value)),
+            _ => Some((key, value)),
+        })
+        .collect()
+}
+
+fn shift_set(set: HashSet<String>, array: &str, removed: usize) -> HashSet<String> {
+    let map = set.into_iter().map(|key| (key, ())).collect();
+    shift_keys(map, array, removed).into_keys().collect()
+}
+
+fn item_index(key: &str, array: &str) -> Option<usize> {
+    key.strip_prefix(array)?.strip_prefix('.')?.parse().ok()
+}
+
+/// Bindings of a field registered with [`UseFormHandle::register`].
+///
+/// Spread `value`, `oninput` and `onblur` on an input, textarea or select.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    /// Name of the field, `array.{index}` for items of field arrays.
+    pub name: String,
+    /// Current value.
+    pub value: String,
+    /// Sets the value from the event target, validating once the field was touched.
+    pub oninput: Callback<InputEvent>,
+    /// Marks the field as touched and validates it.
+    pub onblur: Callback<FocusEvent>,
+    /// Error of the last validation.
+    pub error: Option<String>,
+    /// Whether the value differs from the initial value.
+    pub dirty: bool,
+    /// Whether the field was blurred, or the form submitted.
+    pub touched: bool,
+    /// Whether async validators of the field are running.
+    pub validating: bool,
+}
+
+/// A dynamic list of fields returned by [`UseFormHandle::field_array`].
+pub struct FieldArray<T> {
+    name: String,
+    accessor: fn(&mut T) -> &mut Vec<String>,
+    inner: Rc<FormInner<T>>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<T> FieldArray<T>
+where
+    T: Clone + 'static,
+{
+    /// Bindings of the items, named `array.{index}`.
+    pub fn fields(&self) -> Vec<FormField> {
+        (0..self.len())
+            .map(|index| {
+                self.inner
+                    .field(&format!("{}.{}", self.name, index), self.update.clone())
+            })
+            .collect()
+    }
+
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        (self.accessor)(&mut self.inner.state.borrow_mut().values).len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append an item.
+    pub fn append(&self, value: String) {
+        {
+            let mut state = self.inner.state.borrow_mut();
+            let items = (self.accessor)(&mut state.values);
+            let index = items.len();
+            items.push(value);
+            state.fields.insert(
+                format!("{}.{}", self.name, index),
+                RegisteredField {
+                    accessor: Accessor::Item(self.accessor, index),
+                    rules: self.name.clone(),
+                },
+            );
+        }
+        (self.update)();
+    }
+
+    /// Remove the item at `index`, along with its error and touched flag.
+    pub fn remove(&self, index: usize) {
+        {
+            let mut state = self.inner.state.borrow_mut();
+            let items = (self.accessor)(&mut state.values);
+            if index >= items.len() {
+                return;
+            }
+            items.remove(index);
+            let len = items.len();
+            state.fields.remove(&format!("{}.{}", self.name, len));
+
+            let errors = std::mem::take(&mut state.errors);
+            state.errors = shift_keys(errors, &self.name, index);
+            let touched = std::mem::take(&mut state.touched);
+            state.touched = shift_set(touched, &self.name, index);
+            // Pending validations of the items are dropped, as their names changed.
+            let name = &self.name;
+            state
+                .validations
+                .retain(|key, _| item_index(key, name).is_none());
+            state
+                .validating
+                .retain(|key| item_index(key, name).is_none());
+        }
+        (self.update)();
+    }
+}
+
+/// State handle for the [`use_form`] hook.
+pub struct UseFormHandle<T> {
+    inner: Rc<FormInner<T>>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<T> UseFormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Register the field `name`, bound to the value returned by `accessor`.
+    pub fn register(&self, name: &str, accessor: fn(&mut T) -> &mut String) -> FormField {
+        self.inner.register(name, Accessor::Field(accessor), name);
+        self.inner.field(name, self.update.clone())
+    }
+
+    /// Register a dynamic list of fields `name`, bound to the values returned by `accessor`.
+    pub fn field_array(
+        &self,
+        name: &str,
+        accessor: fn(&mut T) -> &mut Vec<String>,
+    ) -> FieldArray<T> {
+        let len = accessor(&mut self.inner.state.borrow_mut().values).len();
+        for index in 0..len {
+            self.inner.register(
+                &format!("{}.{}", name, index),
+                Accessor::Item(accessor, index),
+                name,
+            );
+        }
+
+        FieldArray {
+            name: name.to_string(),
+            accessor,
+            inner: self.inner.clone(),
+            update: self.update.clone(),
+        }
+    }
+
+    /// Get immutable ref to the form values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values are currently mutably borrowed
+    pub fn values(&'_ self) -> Ref<'_, T> {
+        Ref::map(self.inner.state.borrow(), |state| &state.values)
+    }
+
+    /// Set the value of the field `name`, e.g. from a custom input component.
+    pub fn set_value(&self, name: &str, value: String) {
+        self.inner.set_value(name, value, self.update.clone());
+    }
+
+    /// Error of the field `name`.
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.inner.state.borrow().errors.get(name).cloned()
+    }
+
+    /// Set the error of the field `name`, e.g. from a server response.
+    pub fn set_error(&self, name: &str, error: Option<String>) {
+        self.inner.state.borrow_mut().set_error(name, error);
+        (self.update)();
+    }
+
+    /// Run the validators of the field `name`.
+    pub fn validate(&self, name: &str) {
+        self.inner.validate_field(name, self.update.clone());
+        (self.update)();
+    }
+
+    /// Returns `true` if no field has an error or is being validated.
+    pub fn is_valid(&self) -> bool {
+        let state = self.inner.state.borrow();
+        state.errors.is_empty() && state.validating.is_empty()
+    }
+
+    /// Returns `true` if the values differ from the initial values.
+    pub fn is_dirty(&self) -> bool {
+        let state = self.inner.state.borrow();
+        state.values != state.initial
+    }
+
+    /// Returns `true` if async validators are running.
+    pub fn is_validating(&self) -> bool {
+        !self.inner.state.borrow().validating.is_empty()
+    }
+
+    /// Returns `true` while a submission is validated or handled.
+    pub fn is_submitting(&self) -> bool {
+        self.inner.state.borrow().submitting
+    }
+
+    /// Number of submission attempts since mount or the last reset.
+    pub fn submit_count(&self) -> usize {
+        self.inner.state.borrow().submit_count
+    }
+
+    /// Reset to the initial values, clearing errors and touched flags.
+    pub fn reset(&self) {
+        {
+            let mut state = self.inner.state.borrow_mut();
+            state.values = state.initial.clone();
+            state.errors.clear();
+            state.touched.clear();
+            state.validating.clear();
+            state.validations.clear();
+            state.submit_count = 0;
+        }
+        (self.update)();
+    }
+
+    /// Reset to new initial values, e.g. after they were saved.
+    pub fn reset_to(&self, values: T) {
+        self.inner.state.borrow_mut().initial = values;
+        self.reset();
+    }
+
+    /// Returns a callback for the `onsubmit` of a form, preventing the default submission.
+    ///
+    /// All registered fields are touched and validated,
+    /// `on_submit` is only called with the values if they are valid.
+    /// The form is submitting until `on_submit` completes; submissions are ignored meanwhile.
+    pub fn handle_submit<F, Fut>(&self, on_submit: F) -> Callback<SubmitEvent>
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let inner = self.inner.clone();
+        let update = self.update.clone();
+        let on_submit = Rc::new(on_submit);
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let names = {
+                let mut state = inner.state.borrow_mut();
+                if state.submitting {
+                    return;
+                }
+                let names = state.fields.keys().cloned().collect::<Vec<_>>();
+                state.touched.extend(names.iter().cloned());
+                state.submit_count += 1;
+                names
+            };
+
+            let mut valid = true;
+            for name in &names {
+                let error = inner.sync_error(name);
+                valid &= error.is_none();
+                inner.state.borrow_mut().set_error(name, error);
+            }
+            if !valid {
+                update();
+                return;
+            }
+
+            let validations = names
+                .into_iter()
+                .filter_map(|name| {
+                    let validation = inner.async_validation(&name)?;
+                    Some((name, validation))
+                })
+                .collect::<Vec<_>>();
+            {
+                let mut state = inner.state.borrow_mut();
+                state.submitting = true;
+                for (name, _) in &validations {
+                    state.validating.insert(name.clone());
+                    state.validations.remove(name);
+                }
+            }
+            update();
+
+            let inner = inner.clone();
+            let update = update.clone();
+            let on_submit = on_submit.clone();
+            spawn_local(async move {
+                let mut valid = true;
+                for (name, validation) in validations {
+                    let error = validation.await;
+                    valid &= error.is_none();
+                    let mut state = inner.state.borrow_mut();
+                    state.validating.remove(&name);
+                    state.set_error(&name, error);
+                }
+                update();
+
+                if valid {
+                    let values = inner.state.borrow().values.clone();
+                    on_submit(values).await;
+                }
+
+                inner.state.borrow_mut().submitting = false;
+                update();
+            });
+        })
+    }
+}
+
+impl<T> Clone for UseFormHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            update: self.update.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseFormHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let state = self.inner.state.borrow();
+        let other = other.inner.state.borrow();
+        state.values == other.values
+            && state.errors == other.errors
+            && state.touched == other.touched
+            && state.validating == other.validating
+            && state.submitting == other.submitting
+    }
+}
+
+/// This hook is used to manage a form, with field bindings, validation and submission.
+/// See [`use_form_with_options`] to validate the fields.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Default)]
+/// struct Contact {
+///     name: String,
+///     message: String,
+/// }
+///
+/// #[function_component(ContactForm)]
+/// fn contact_form() -> Html {
+///     let form = use_form(Contact::default());
+///     let name = form.register("name", |contact| &mut contact.name);
+///     let message = form.register("message", |contact| &mut contact.message);
+///
+///     let onsubmit = form.handle_submit(|contact: Contact| async move {
+///         log::info!("Sending message from {}", contact.name);
+///     });
+///
+///     html! {
+///         <form {onsubmit}>
+///             <input value={name.value} oninput={name.oninput} onblur={name.onblur} />
+///             <textarea value={message.value} oninput={message.oninput} onblur={message.onblur} />
+///             <button type="submit" disabled={form.is_submitting()}>{ "Send" }</button>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_form<T>(initial_values: T) -> UseFormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    use_form_with_options(initial_values, UseFormOptions::default())
+}
+
+/// This hook is used to manage a form with validation. See [`use_form`] too.
+///
+/// Fields are validated when blurred, then on every input; all fields are validated on submit.
+/// The `options` are only read on mount.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::platform::time::sleep;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Default)]
+/// struct SignUp {
+///     username: String,
+///     emails: Vec<String>,
+/// }
+///
+/// #[function_component(SignUpForm)]
+/// fn sign_up_form() -> Html {
+///     let form = use_form_with_options(
+///         SignUp {
+///             username: String::new(),
+///             emails: vec![String::new()],
+///         },
+///         UseFormOptions::new()
+///             .validator("username", |value, _| {
+///                 if value.len() < 3 {
+///                     Err("At least 3 characters".to_string())
+///                 } else {
+///                     Ok(())
+///                 }
+///             })
+///             .async_validator("username", |value, _| async move {
+///                 // Ask the server whether the username is available.
+///                 sleep(Duration::from_millis(500)).await;
+///                 if value == "admin" {
+///                     Err("Username taken".to_string())
+///                 } else {
+///                     Ok(())
+///                 }
+///             })
+///             .validator("emails", |value, _| {
+///                 if value.contains('@') {
+///                     Ok(())
+///                 } else {
+///                     Err("Invalid email".to_string())
+///                 }
+///             }),
+///     );
+///     let username = form.register("username", |values| &mut values.username);
+///     let emails = form.field_array("emails", |values| &mut values.emails);
+///
+///     let onsubmit = form.handle_submit(|values: SignUp| async move {
+///         log::info!("Signing up {}", values.username);
+///     });
+///     let onadd = {
+///         let emails = form.field_array("emails", |values| &mut values.emails);
+///         Callback::from(move |_| emails.append(String::new()))
+///     };
+///
+///     html! {
+///         <form {onsubmit}>
+///             <input value={username.value} oninput={username.oninput} onblur={username.onblur} />
+///             if username.validating {
+///                 <p>{ "Checking..." }</p>
+///             }
+///             if let Some(error) = username.error {
+///                 <p>{ error }</p>
+///             }
+///             {
+///                 for emails.fields().into_iter().enumerate().map(|(index, email)| {
+///                     let onremove = {
+///                         let emails = form.field_array("emails", |values| &mut values.emails);
+///                         Callback::from(move |_| emails.remove(index))
+///                     };
+///                     html! {
+///                         <div>
+///                             <input value={email.value} oninput={email.oninput} onblur={email.onblur} />
+///                             <button type="button" onclick={onremove}>{ "Remove" }</button>
+///                             { email.error.unwrap_or_default() }
+///                         </div>
+///                     }
+///                 })
+///             }
+///             <button type="button" onclick={onadd}>{ "Add email" }</button>
+///             <button type="submit" disabled={form.is_submitting()}>{ "Sign up" }</button>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_form_with_options<T>(initial_values: T, options: UseFormOptions<T>) -> UseFormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let inner = use_memo((), move |_| {
+        Rc::new(FormInner {
+            state: RefCell::new(FormState {
+                values: initial_values.clone(),
+                initial: initial_values,
+                fields: BTreeMap::new(),
+                errors: HashMap::new(),
+                touched: HashSet::new(),
+                validating: HashSet::new(),
+                validations: HashMap::new(),
+                next_validation: 0,
+                submitting: false,
+                submit_count: 0,
+            }),
+            options,
+        })
+    });
+    let update = use_update();
+    // Async validation and submits outlive the render they started in.
+    let update = use_latest(update);
+    let update: Rc<dyn Fn()> = Rc::new(move || (*update.current())());
+
+    UseFormHandle {
+        inner: (*inner).clone(),
+        update,
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::TimestampedValue;
+
+/// State handle for the [`use_history_of`] hook.
+pub struct UseHistoryOfHandle<T> {
+    inner: Rc<VecDeque<TimestampedValue<T>>>,
+}
+
+impl<T> UseHistoryOfHandle<T> {
+    /// The current value, recorded when it last changed.
+    pub fn current(&self) -> &TimestampedValue<T> {
+        self.inner
+            .back()
+            .expect("history always contains the current value")
+    }
+
+    /// The value before the current one, `None` if the value never changed.
+    pub fn previous(&self) -> Option<&TimestampedValue<T>> {
+        self.inner.iter().nth_back(1)
+    }
+}
+
+impl<T> Deref for UseHistoryOfHandle<T> {
+    type Target = VecDeque<TimestampedValue<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseHistoryOfHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseHistoryOfHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+impl<T> fmt::Debug for UseHistoryOfHandle<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseHistoryOfHandle")
+            .field("history", &self.inner)
+            .finish()
+    }
+}
+
+/// This hook tracks the last `capacity` distinct values of state or props,
+/// oldest first, along with when each was recorded.
+/// The current value is always the last one.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(HistoryOf)]
+/// fn history_of() -> Html {
+///     let state = use_state(|| 0);
+///     let history = use_history_of(*state, 5);
+///
+///     let onincrease = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(*state + 1))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onincrease}>{ "Increase" }</button>
+///             <p>
+///                 <b>{ "Last values: " }</b>
+///                 { for history.iter().map(|recorded| html! { <span>{ recorded.value }{ " " }</span> }) }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_history_of<T>(value: T, capacity: usize) -> UseHistoryOfHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let mut value = Some(value);
+    let inner = use_mut_ref(|| {
+        Rc::new(VecDeque::from([TimestampedValue::now(
+            value.take().unwrap(),
+        )]))
+    });
+
+    // The value is only left after the first render.
+    if let Some(value) = value {
+        let mut inner = inner.borrow_mut();
+        if inner.back().is_none_or(|current| current.value != value) {
+            let history = Rc::make_mut(&mut inner);
+            history.push_back(TimestampedValue::now(value));
+            while history.len() > capacity.max(1) {
+                history.pop_front();
+            }
+        }
+    }
+
+    let inner = inner.borrow().clone();
+    UseHistoryOfHandle { inner }
+}
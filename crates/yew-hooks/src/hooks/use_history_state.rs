@@ -0,0 +1,283 @@
+use std::cell::{Ref, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::use_update;
+
+/// Options for [`use_history_state_with_options`].
+#[derive(Default)]
+pub struct UseHistoryStateOptions {
+    /// Maximum number of past values kept for undo, unbounded if `None`.
+    /// The oldest values are dropped first.
+    pub capacity: Option<usize>,
+    /// Changes made within this many milliseconds of the previous change are coalesced
+    /// into one checkpoint, so e.g. typing a word is undone at once. Disabled if `0`.
+    pub checkpoint_millis: u32,
+}
+
+impl UseHistoryStateOptions {
+    /// Keep at most `capacity` past values.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            checkpoint_millis: 0,
+        }
+    }
+
+    /// Coalesce changes made within `millis` of each other.
+    pub const fn with_checkpoint_millis(millis: u32) -> Self {
+        Self {
+            capacity: None,
+            checkpoint_millis: millis,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    /// When the last change was made, `None` after a checkpoint.
+    last_changed: Option<f64>,
+}
+
+/// State handle for the [`use_history_state`] hook.
+pub struct UseHistoryStateHandle<T> {
+    inner: Rc<RefCell<History<T>>>,
+    update: Rc<dyn Fn()>,
+    capacity: Option<usize>,
+    checkpoint_millis: u32,
+}
+
+impl<T> UseHistoryStateHandle<T> {
+    /// Get immutable ref to the current value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed
+    pub fn current(&'_ self) -> Ref<'_, T> {
+        Ref::map(self.inner.borrow(), |history| &history.present)
+    }
+
+    /// Set the current value, recording the previous one for undo
+    /// unless it is coalesced with the last change. Clears the redo history.
+    pub fn set(&self, value: T) {
+        {
+            let mut history = self.inner.borrow_mut();
+            let now = js_sys::Date::now();
+            let coalesce = self.checkpoint_millis > 0
+                && history
+                    .last_changed
+                    .is_some_and(|last| now - last < f64::from(self.checkpoint_millis));
+
+            let previous = std::mem::replace(&mut history.present, value);
+            if !coalesce {
+                history.past.push_back(previous);
+                if let Some(capacity) = self.capacity {
+                    while history.past.len() > capacity {
+                        history.past.pop_front();
+                    }
+                }
+            }
+            history.future.clear();
+            history.last_changed = Some(now);
+        }
+        (self.update)();
+    }
+
+    /// Restore the previous value. Does nothing if there is nothing to undo.
+    pub fn undo(&self) {
+        {
+            let mut history = self.inner.borrow_mut();
+            if let Some(previous) = history.past.pop_back() {
+                let present = std::mem::replace(&mut history.present, previous);
+                history.future.push(present);
+            }
+            history.last_changed = None;
+        }
+        (self.update)();
+    }
+
+    /// Restore the value undone last. Does nothing if there is nothing to redo.
+    pub fn redo(&self) {
+        {
+            let mut history = self.inner.borrow_mut();
+            if let Some(next) = history.future.pop() {
+                let present = std::mem::replace(&mut history.present, next);
+                history.past.push_back(present);
+            }
+            history.last_changed = None;
+        }
+        (self.update)();
+    }
+
+    /// Returns `true` if there is a previous value to restore.
+    pub fn can_undo(&self) -> bool {
+        !self.inner.borrow().past.is_empty()
+    }
+
+    /// Returns `true` if there is an undone value to restore.
+    pub fn can_redo(&self) -> bool {
+        !self.inner.borrow().future.is_empty()
+    }
+
+    /// End the current checkpoint, so the next change is recorded separately
+    /// even if made within `checkpoint_millis`.
+    pub fn checkpoint(&self) {
+        self.inner.borrow_mut().last_changed = None;
+    }
+
+    /// Clear the undo and redo history, keeping the current value.
+    pub fn clear_history(&self) {
+        {
+            let mut history = self.inner.borrow_mut();
+            history.past.clear();
+            history.future.clear();
+            history.last_changed = None;
+        }
+        (self.update)();
+    }
+}
+
+impl<T> Clone for UseHistoryStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            update: self.update.clone(),
+            capacity: self.capacity,
+            checkpoint_millis: self.checkpoint_millis,
+        }
+    }
+}
+
+impl<T> PartialEq for UseHistoryStateHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+/// A hook that tracks a value along with its undo and redo history.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(HistoryState)]
+/// fn history_state() -> Html {
+///     let state = use_history_state(0);
+///
+///     let onincrease = {
+///         let state = state.clone();
+///         Callback::from(move |_| {
+///             let value = *state.current() + 1;
+///             state.set(value);
+///         })
+///     };
+///     let onundo = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.undo())
+///     };
+///     let onredo = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.redo())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onincrease}>{ "Increase" }</button>
+///             <button onclick={onundo} disabled={!state.can_undo()}>{ "Undo" }</button>
+///             <button onclick={onredo} disabled={!state.can_redo()}>{ "Redo" }</button>
+///             <p>
+///                 <b>{ "Current value: " }</b>
+///                 { *state.current() }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_history_state<T>(initial_value: T) -> UseHistoryStateHandle<T>
+where
+    T: 'static,
+{
+    use_history_state_with_options(initial_value, UseHistoryStateOptions::default())
+}
+
+/// A hook that tracks a value along with its undo and redo history, with options.
+/// See [`use_history_state`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_sys::HtmlInputElement;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Editor)]
+/// fn editor() -> Html {
+///     let text = use_history_state_with_options(
+///         String::new(),
+///         UseHistoryStateOptions {
+///             capacity: Some(100),
+///             checkpoint_millis: 500,
+///         },
+///     );
+///
+///     let oninput = {
+///         let text = text.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let input: HtmlInputElement = e.target_unchecked_into();
+///             text.set(input.value());
+///         })
+///     };
+///     let onundo = {
+///         let text = text.clone();
+///         Callback::from(move |_| text.undo())
+///     };
+///     let onredo = {
+///         let text = text.clone();
+///         Callback::from(move |_| text.redo())
+///     };
+///
+///     html! {
+///         <div>
+///             <input value={text.current().clone()} {oninput} />
+///             <button onclick={onundo} disabled={!text.can_undo()}>{ "Undo" }</button>
+///             <button onclick={onredo} disabled={!text.can_redo()}>{ "Redo" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_history_state_with_options<T>(
+    initial_value: T,
+    options: UseHistoryStateOptions,
+) -> UseHistoryStateHandle<T>
+where
+    T: 'static,
+{
+    let inner = use_mut_ref(|| History {
+        past: VecDeque::new(),
+        present: initial_value,
+        future: vec![],
+        last_changed: None,
+    });
+    let update = use_update();
+
+    UseHistoryStateHandle {
+        inner,
+        update,
+        capacity: options.capacity,
+        checkpoint_millis: options.checkpoint_millis,
+    }
+}
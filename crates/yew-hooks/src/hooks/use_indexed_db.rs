@@ -0,0 +1,1075 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use gloo::utils::window;
+use js_sys::{Array, Promise};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    Event, IdbKeyRange, IdbObjectStore, IdbRequest, IdbTransactionMode, IdbVersionChangeEvent,
+};
+use yew::prelude::*;
+
+use super::{use_event_with_window, use_latest};
+
+pub use web_sys::{IdbDatabase, IdbTransaction};
+
+/// Error for the IndexedDB hooks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdbError {
+    /// IndexedDB is not supported or not accessible.
+    Unavailable,
+    /// The database is not open yet, or failed to open.
+    NotOpen,
+    /// The value could not be serialized or deserialized.
+    Serde(String),
+    /// An error reported by IndexedDB, formatted as `name: message`.
+    Js(String),
+    /// Opening the database is blocked by a connection to an older version in another tab.
+    /// The database opens once that connection is closed.
+    Blocked,
+    /// The connection was closed because another tab upgraded the database.
+    Closed,
+}
+
+impl IdbError {
+    fn from_js(error: JsValue) -> Self {
+        let get = |prop: &str| {
+            js_sys::Reflect::get(&error, &JsValue::from_str(prop))
+                .ok()
+                .and_then(|v| v.as_string())
+                .unwrap_or_default()
+        };
+        Self::Js(format!("{}: {}", get("name"), get("message")))
+    }
+}
+
+impl fmt::Display for IdbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "IndexedDB is not available"),
+            Self::NotOpen => write!(f, "database is not open"),
+            Self::Serde(message) => write!(f, "failed to (de)serialize value: {}", message),
+            Self::Js(message) => write!(f, "{}", message),
+            Self::Blocked => write!(f, "database is blocked by a connection in another tab"),
+            Self::Closed => write!(f, "database was closed by an upgrade in another tab"),
+        }
+    }
+}
+
+impl std::error::Error for IdbError {}
+
+/// Information passed to the upgrade callback of [`use_indexed_db`].
+pub struct UseIndexedDbUpgrade {
+    /// The database being upgraded, used to create or delete object stores.
+    pub db: IdbDatabase,
+    /// The `versionchange` transaction, used to modify existing object stores.
+    pub transaction: Option<IdbTransaction>,
+    /// The version of the database before the upgrade, `0` if it was just created.
+    pub old_version: u32,
+    /// The version of the database being opened.
+    pub new_version: u32,
+}
+
+/// State of an IndexedDB database connection.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UseIndexedDbState {
+    /// If the database is being opened, or is blocked by another tab.
+    pub loading: bool,
+    /// The open database, `None` until it is opened or once it is closed.
+    pub db: Option<IdbDatabase>,
+    /// The error of opening the database, or why it was closed.
+    pub error: Option<IdbError>,
+}
+
+/// State handle for the [`use_indexed_db`] hook.
+pub struct UseIndexedDbHandle {
+    inner: UseStateHandle<UseIndexedDbState>,
+    name: Rc<String>,
+}
+
+impl Deref for UseIndexedDbHandle {
+    type Target = UseIndexedDbState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl Clone for UseIndexedDbHandle {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl PartialEq for UseIndexedDbHandle {
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner && self.name == other.name
+    }
+}
+
+/// A key range for [`use_idb_query`], keys are anything serializable to a valid IndexedDB key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum IdbRange {
+    /// All records.
+    #[default]
+    All,
+    /// Only the record with the key.
+    Only(Value),
+    /// Records with keys greater than `lower`, inclusive unless `open`.
+    LowerBound { lower: Value, open: bool },
+    /// Records with keys less than `upper`, inclusive unless `open`.
+    UpperBound { upper: Value, open: bool },
+    /// Records with keys between `lower` and `upper`.
+    Bound {
+        lower: Value,
+        upper: Value,
+        lower_open: bool,
+        upper_open: bool,
+    },
+    /// A key that could not be serialized, reported as the error of the query.
+    Invalid(IdbError),
+}
+
+impl IdbRange {
+    /// Only the record with the `key`.
+    pub fn only<K: Serialize>(key: K) -> Self {
+        to_json(&key).map_or_else(Self::Invalid, Self::Only)
+    }
+
+    /// Records with keys greater than (or equal to, unless `open`) `lower`.
+    pub fn lower_bound<K: Serialize>(lower: K, open: bool) -> Self {
+        match to_json(&lower) {
+            Ok(lower) => Self::LowerBound { lower, open },
+            Err(e) => Self::Invalid(e),
+        }
+    }
+
+    /// Records with keys less than (or equal to, unless `open`) `upper`.
+    pub fn upper_bound<K: Serialize>(upper: K, open: bool) -> Self {
+        match to_json(&upper) {
+            Ok(upper) => Self::UpperBound { upper, open },
+            Err(e) => Self::Invalid(e),
+        }
+    }
+
+    /// Records with keys between `lower` and `upper`.
+    pub fn bound<K: Serialize>(lower: K, upper: K, lower_open: bool, upper_open: bool) -> Self {
+        match (to_json(&lower), to_json(&upper)) {
+            (Ok(lower), Ok(upper)) => Self::Bound {
+                lower,
+                upper,
+                lower_open,
+                upper_open,
+            },
+            (Err(e), _) | (_, Err(e)) => Self::Invalid(e),
+        }
+    }
+
+    fn to_js(&self) -> Result<JsValue, IdbError> {
+        let range = match self {
+            Self::All => return Ok(JsValue::UNDEFINED),
+            Self::Invalid(e) => return Err(e.clone()),
+            Self::Only(key) => IdbKeyRange::only(&to_js(key)?),
+            Self::LowerBound { lower, open } => {
+                IdbKeyRange::lower_bound_with_open(&to_js(lower)?, *open)
+            }
+            Self::UpperBound { upper, open } => {
+                IdbKeyRange::upper_bound_with_open(&to_js(upper)?, *open)
+            }
+            Self::Bound {
+                lower,
+                upper,
+                lower_open,
+                upper_open,
+            } => IdbKeyRange::bound_with_lower_open_and_upper_open(
+                &to_js(lower)?,
+                &to_js(upper)?,
+                *lower_open,
+                *upper_open,
+            ),
+        };
+        range.map(Into::into).map_err(IdbError::from_js)
+    }
+}
+
+/// Options for [`use_idb_query_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UseIdbQueryOptions {
+    /// Query an index of the object store instead of its primary keys.
+    pub index: Option<String>,
+    /// Maximum number of records to return.
+    pub limit: Option<u32>,
+}
+
+/// State handle for the [`use_idb_value`] hook.
+pub struct UseIdbValueHandle<T> {
+    inner: UseStateHandle<Option<T>>,
+    /// If the value is being loaded from the database.
+    pub loading: UseStateHandle<bool>,
+    /// The error of the last failed operation.
+    pub error: UseStateHandle<Option<IdbError>>,
+    db: Option<IdbDatabase>,
+    store: Rc<String>,
+    key: Rc<Result<Value, IdbError>>,
+    refresh: Rc<dyn Fn()>,
+}
+
+impl<T> UseIdbValueHandle<T> {
+    /// Put a `value` for the key into the object store.
+    /// The state is updated once the write succeeds.
+    pub fn set(&self, value: T)
+    where
+        T: Serialize + 'static,
+    {
+        let inner = self.inner.clone();
+        let error = self.error.clone();
+        let db = self.db.clone();
+        let store = self.store.clone();
+        let key = self.key.clone();
+        spawn_local(async move {
+            let result = match (&db, &*key) {
+                (_, Err(e)) => Err(e.clone()),
+                (Some(db), Ok(key)) => idb_put(db, &store, key, &value).await,
+                (None, _) => Err(IdbError::NotOpen),
+            };
+            match result {
+                Ok(()) => {
+                    inner.set(Some(value));
+                    error.set(None);
+                    notify_change(db.as_ref(), &store);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    }
+
+    /// Delete the key and its stored value from the object store.
+    pub fn delete(&self)
+    where
+        T: 'static,
+    {
+        let inner = self.inner.clone();
+        let error = self.error.clone();
+        let db = self.db.clone();
+        let store = self.store.clone();
+        let key = self.key.clone();
+        spawn_local(async move {
+            let result = match (&db, &*key) {
+                (_, Err(e)) => Err(e.clone()),
+                (Some(db), Ok(key)) => idb_delete(db, &store, key).await,
+                (None, _) => Err(IdbError::NotOpen),
+            };
+            match result {
+                Ok(()) => {
+                    inner.set(None);
+                    error.set(None);
+                    notify_change(db.as_ref(), &store);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    }
+
+    /// Reload the value from the object store.
+    pub fn refresh(&self) {
+        (self.refresh)();
+    }
+}
+
+impl<T> Deref for UseIdbValueHandle<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseIdbValueHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            loading: self.loading.clone(),
+            error: self.error.clone(),
+            db: self.db.clone(),
+            store: self.store.clone(),
+            key: self.key.clone(),
+            refresh: self.refresh.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseIdbValueHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+            && *self.loading == *other.loading
+            && *self.error == *other.error
+    }
+}
+
+/// State handle for the [`use_idb_query`] hook.
+pub struct UseIdbQueryHandle<T> {
+    inner: UseStateHandle<Vec<T>>,
+    /// If the records are being loaded from the database.
+    pub loading: UseStateHandle<bool>,
+    /// The error of the last failed query.
+    pub error: UseStateHandle<Option<IdbError>>,
+    refresh: Rc<dyn Fn()>,
+}
+
+impl<T> UseIdbQueryHandle<T> {
+    /// Run the query again.
+    pub fn refresh(&self) {
+        (self.refresh)();
+    }
+}
+
+impl<T> Deref for UseIdbQueryHandle<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> Clone for UseIdbQueryHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            loading: self.loading.clone(),
+            error: self.error.clone(),
+            refresh: self.refresh.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseIdbQueryHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+            && *self.loading == *other.loading
+            && *self.error == *other.error
+    }
+}
+
+/// Integers beyond `Number.MAX_SAFE_INTEGER` lose precision as JS numbers.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Check that all numbers of a value are exact as JS numbers,
+/// so that keys do not collide and values read back as written.
+fn check_precision(value: &Value) -> Result<(), IdbError> {
+    match value {
+        Value::Number(number) => {
+            let exact = match (number.as_u64(), number.as_i64()) {
+                (Some(n), _) => n <= MAX_SAFE_INTEGER,
+                (None, Some(n)) => n.unsigned_abs() <= MAX_SAFE_INTEGER,
+                (None, None) => true,
+            };
+            if exact {
+                Ok(())
+            } else {
+                Err(IdbError::Serde(format!(
+                    "{} cannot be represented exactly as a JS number",
+                    number
+                )))
+            }
+        }
+        Value::Array(values) => values.iter().try_for_each(check_precision),
+        Value::Object(values) => values.values().try_for_each(check_precision),
+        _ => Ok(()),
+    }
+}
+
+/// Convert a serializable value to JSON, rejecting integers JS numbers cannot represent.
+fn to_json<K: Serialize>(key: &K) -> Result<Value, IdbError> {
+    let value = serde_json::to_value(key).map_err(|e| IdbError::Serde(e.to_string()))?;
+    check_precision(&value)?;
+    Ok(value)
+}
+
+/// Convert a serializable value to a structured-clonable JS value.
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, IdbError> {
+    let json = to_json(value)?.to_string();
+    js_sys::JSON::parse(&json).map_err(IdbError::from_js)
+}
+
+/// Convert a JS value read from the database, `undefined` means there is no record.
+fn from_js<T>(value: &JsValue) -> Result<Option<T>, IdbError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let json: String = js_sys::JSON::stringify(value)
+        .map_err(IdbError::from_js)?
+        .into();
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| IdbError::Serde(e.to_string()))
+}
+
+/// Name of the window event dispatched after a write to an object store.
+fn change_event_type(db_name: &str, store: &str) -> String {
+    format!("yew-hooks-idb:{}:{}", db_name, store)
+}
+
+/// Let other hooks watching the object store know that it has changed.
+fn notify_change(db: Option<&IdbDatabase>, store: &str) {
+    if let Some(db) = db {
+        if let Ok(event) = Event::new(&change_event_type(&db.name(), store)) {
+            let _ = window().dispatch_event(&event);
+        }
+    }
+}
+
+/// Wait for an `IDBRequest` to complete and return its result.
+async fn wait_request(request: &IdbRequest) -> Result<JsValue, IdbError> {
+    // Only one of the handlers fires, both are kept until then and dropped together.
+    let mut handlers = None;
+    let promise = Promise::new(&mut |resolve, reject| {
+        let onsuccess = Closure::<dyn Fn()>::new(move || {
+            let _ = resolve.call0(&JsValue::UNDEFINED);
+        });
+        let onerror = Closure::<dyn Fn()>::new(move || {
+            let _ = reject.call0(&JsValue::UNDEFINED);
+        });
+        request.set_onsuccess(Some(onsuccess.as_ref().unchecked_ref()));
+        request.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        handlers = Some((onsuccess, onerror));
+    });
+
+    let settled = JsFuture::from(promise).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+    drop(handlers);
+
+    if settled.is_err() {
+        return Err(match request.error() {
+            Ok(Some(error)) => IdbError::from_js(error.into()),
+            Ok(None) => IdbError::Js("request failed".to_string()),
+            Err(error) => IdbError::from_js(error),
+        });
+    }
+    request.result().map_err(IdbError::from_js)
+}
+
+/// Open a database, `blocked` is called when another tab keeps an older version open.
+async fn open_database(
+    name: &str,
+    version: u32,
+    upgrade: impl Fn(UseIndexedDbUpgrade) + 'static,
+    blocked: impl Fn() + 'static,
+) -> Result<IdbDatabase, IdbError> {
+    let factory = window()
+        .indexed_db()
+        .ok()
+        .flatten()
+        .ok_or(IdbError::Unavailable)?;
+    let request = factory
+        .open_with_u32(name, version)
+        .map_err(IdbError::from_js)?;
+
+    let onupgradeneeded = {
+        let request = request.clone();
+        Closure::<dyn Fn(IdbVersionChangeEvent)>::new(move |e: IdbVersionChangeEvent| {
+            if let Ok(db) = request.result() {
+                upgrade(UseIndexedDbUpgrade {
+                    db: db.unchecked_into(),
+                    transaction: request.transaction(),
+                    old_version: e.old_version() as u32,
+                    new_version: e.new_version().unwrap_or_default() as u32,
+                });
+            }
+        })
+    };
+    request.set_onupgradeneeded(Some(onupgradeneeded.as_ref().unchecked_ref()));
+    let onblocked = Closure::<dyn Fn()>::new(blocked);
+    request.set_onblocked(Some(onblocked.as_ref().unchecked_ref()));
+
+    let result = wait_request(&request).await;
+    request.set_onupgradeneeded(None);
+    request.set_onblocked(None);
+    result.map(JsCast::unchecked_into)
+}
+
+fn object_store(
+    db: &IdbDatabase,
+    store: &str,
+    mode: IdbTransactionMode,
+) -> Result<IdbObjectStore, IdbError> {
+    db.transaction_with_str_and_mode(store, mode)
+        .and_then(|transaction| transaction.object_store(store))
+        .map_err(IdbError::from_js)
+}
+
+async fn idb_get<T>(db: &IdbDatabase, store: &str, key: &Value) -> Result<Option<T>, IdbError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let request = object_store(db, store, IdbTransactionMode::Readonly)?
+        .get(&to_js(key)?)
+        .map_err(IdbError::from_js)?;
+    from_js(&wait_request(&request).await?)
+}
+
+async fn idb_put<T>(db: &IdbDatabase, store: &str, key: &Value, value: &T) -> Result<(), IdbError>
+where
+    T: Serialize,
+{
+    let object_store = object_store(db, store, IdbTransactionMode::Readwrite)?;
+    let value = to_js(value)?;
+    // Stores with a key path take the key from the value itself.
+    let in_line_keys = object_store
+        .key_path()
+        .map(|key_path| !key_path.is_null())
+        .unwrap_or(false);
+    let request = if in_line_keys {
+        object_store.put(&value)
+    } else {
+        object_store.put_with_key(&value, &to_js(key)?)
+    }
+    .map_err(IdbError::from_js)?;
+    wait_request(&request).await.map(|_| ())
+}
+
+async fn idb_delete(db: &IdbDatabase, store: &str, key: &Value) -> Result<(), IdbError> {
+    let request = object_store(db, store, IdbTransactionMode::Readwrite)?
+        .delete(&to_js(key)?)
+        .map_err(IdbError::from_js)?;
+    wait_request(&request).await.map(|_| ())
+}
+
+async fn idb_get_all<T>(
+    db: &IdbDatabase,
+    store: &str,
+    range: &IdbRange,
+    options: &UseIdbQueryOptions,
+) -> Result<Vec<T>, IdbError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let object_store = object_store(db, store, IdbTransactionMode::Readonly)?;
+    let query = range.to_js()?;
+    let request = match &options.index {
+        Some(index) => {
+            let index = object_store.index(index).map_err(IdbError::from_js)?;
+            match options.limit {
+                Some(limit) => index.get_all_with_key_and_limit(&query, limit),
+                None => index.get_all_with_key(&query),
+            }
+        }
+        None => match options.limit {
+            Some(limit) => object_store.get_all_with_key_and_limit(&query, limit),
+            None => object_store.get_all_with_key(&query),
+        },
+    }
+    .map_err(IdbError::from_js)?;
+
+    let records: Array = wait_request(&request).await?.unchecked_into();
+    records
+        .iter()
+        .filter_map(|record| from_js(&record).transpose())
+        .collect()
+}
+
+/// A side-effect hook that opens an IndexedDB database.
+/// The `upgrade` callback is invoked when the database is created or its `version` is increased,
+/// and is where object stores and indexes should be created.
+///
+/// The returned handle is passed to [`use_idb_value`] and [`use_idb_query`],
+/// the connection is closed when the component unmounts.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(IndexedDb)]
+/// fn indexed_db() -> Html {
+///     let db = use_indexed_db("app".to_string(), 1, |upgrade| {
+///         if upgrade.old_version < 1 {
+///             let _ = upgrade.db.create_object_store("documents");
+///         }
+///     });
+///
+///     html! {
+///         <div>
+///             <p>
+///                 <b>{ "Loading: " }</b>
+///                 { db.loading }
+///             </p>
+///             {
+///                 if let Some(error) = &db.error {
+///                     html! { error.to_string() }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_indexed_db<F>(name: String, version: u32, upgrade: F) -> UseIndexedDbHandle
+where
+    F: Fn(UseIndexedDbUpgrade) + 'static,
+{
+    let inner = use_state(|| UseIndexedDbState {
+        loading: true,
+        db: None,
+        error: None,
+    });
+    let upgrade = use_latest(upgrade);
+    let name = use_memo(name, |name| name.clone());
+
+    {
+        let inner = inner.clone();
+        use_effect_with((name.clone(), version), move |(name, version)| {
+            let cancelled = Rc::new(Cell::new(false));
+            // The open connection and its `versionchange` handler.
+            let opened = Rc::new(RefCell::new(None::<(IdbDatabase, Closure<dyn Fn()>)>));
+
+            if !inner.loading {
+                inner.set(UseIndexedDbState {
+                    loading: true,
+                    db: None,
+                    error: None,
+                });
+            }
+
+            {
+                let name = name.clone();
+                let version = *version;
+                let cancelled = cancelled.clone();
+                let opened = opened.clone();
+                spawn_local(async move {
+                    let blocked = {
+                        let inner = inner.clone();
+                        let cancelled = cancelled.clone();
+                        move || {
+                            if !cancelled.get() {
+                                inner.set(UseIndexedDbState {
+                                    loading: true,
+                                    db: None,
+                                    error: Some(IdbError::Blocked),
+                                });
+                            }
+                        }
+                    };
+                    let result =
+                        open_database(&name, version, move |e| (*upgrade.current())(e), blocked)
+                            .await;
+                    if cancelled.get() {
+                        if let Ok(db) = result {
+                            db.close();
+                        }
+                        return;
+                    }
+                    match result {
+                        Ok(db) => {
+                            // Let another tab upgrade the database instead of blocking it.
+                            let onversionchange = {
+                                let db = db.clone();
+                                let inner = inner.clone();
+                                let opened = opened.clone();
+                                Closure::<dyn Fn()>::new(move || {
+                                    db.close();
+                                    inner.set(UseIndexedDbState {
+                                        loading: false,
+                                        db: None,
+                                        error: Some(IdbError::Closed),
+                                    });
+                                    // The handler is running, drop it afterwards.
+                                    let opened = opened.clone();
+                                    spawn_local(async move {
+                                        opened.borrow_mut().take();
+                                    });
+                                })
+                            };
+                            db.set_onversionchange(Some(onversionchange.as_ref().unchecked_ref()));
+                            *opened.borrow_mut() = Some((db.clone(), onversionchange));
+                            inner.set(UseIndexedDbState {
+                                loading: false,
+                                db: Some(db),
+                                error: None,
+                            });
+                        }
+                        Err(error) => inner.set(UseIndexedDbState {
+                            loading: false,
+                            db: None,
+                            error: Some(error),
+                        }),
+                    }
+                });
+            }
+
+            move || {
+                cancelled.set(true);
+                if let Some((db, _)) = opened.borrow_mut().take() {
+                    db.set_onversionchange(None);
+                    db.close();
+                }
+            }
+        });
+    }
+
+    UseIndexedDbHandle { inner, name }
+}
+
+/// A side-effect hook that manages a single key of an IndexedDB object store.
+/// The value is loaded once the database is open and reloaded when the `store` or `key` changes,
+/// or when another hook writes to the same object store.
+///
+/// Keys and values are stored as JSON-like JS values, so integers beyond
+/// `Number.MAX_SAFE_INTEGER` are rejected with [`IdbError::Serde`] rather than rounded.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// struct Document {
+///     title: String,
+///     body: String,
+/// }
+///
+/// #[function_component(IdbValue)]
+/// fn idb_value() -> Html {
+///     let db = use_indexed_db("app".to_string(), 1, |upgrade| {
+///         let _ = upgrade.db.create_object_store("documents");
+///     });
+///     let document = use_idb_value::<Document, _>(&db, "documents".to_string(), "draft");
+///
+///     let onclick = {
+///         let document = document.clone();
+///         Callback::from(move |_| document.set(Document {
+///             title: "Draft".to_string(),
+///             body: "Hello".to_string(),
+///         }))
+///     };
+///     let ondelete = {
+///         let document = document.clone();
+///         Callback::from(move |_| document.delete())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Save draft" }</button>
+///             <button onclick={ondelete}>{ "Delete draft" }</button>
+///             {
+///                 if *document.loading {
+///                     html! { "Loading" }
+///                 } else if let Some(document) = &*document {
+///                     html! { <p><b>{ &document.title }</b>{ " " }{ &document.body }</p> }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_idb_value<T, K>(db: &UseIndexedDbHandle, store: String, key: K) -> UseIdbValueHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+    K: Serialize,
+{
+    let inner: UseStateHandle<Option<T>> = use_state(|| None);
+    let loading = use_state(|| true);
+    let error = use_state(|| None);
+    let version = use_mut_ref(|| 0u32);
+    let store = use_memo(store, |store| store.clone());
+    let key = use_memo(to_json(&key), |key| key.clone());
+
+    let refresh: Rc<dyn Fn()> = {
+        let inner = inner.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let db_loading = db.loading;
+        let db = db.db.clone();
+        let store = store.clone();
+        let key = key.clone();
+        Rc::new(move || {
+            if let Err(e) = &*key {
+                loading.set(false);
+                error.set(Some(e.clone()));
+                return;
+            }
+            let db = match db.clone() {
+                Some(db) => db,
+                None => {
+                    // Stop loading if the database failed to open.
+                    if !db_loading {
+                        loading.set(false);
+                        error.set(Some(IdbError::NotOpen));
+                    }
+                    return;
+                }
+            };
+            let current = {
+                let mut version = version.borrow_mut();
+                *version = version.wrapping_add(1);
+                *version
+            };
+            if !*loading {
+                loading.set(true);
+            }
+
+            let inner = inner.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let version = version.clone();
+            let store = store.clone();
+            let key = key.clone();
+            spawn_local(async move {
+                let result = match &*key {
+                    Ok(key) => idb_get(&db, &store, key).await,
+                    Err(e) => Err(e.clone()),
+                };
+                // Ignore results of outdated loads.
+                if *version.borrow() != current {
+                    return;
+                }
+                match result {
+                    Ok(value) => {
+                        inner.set(value);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let refresh = refresh.clone();
+        use_effect_with((db.db.clone(), store.clone(), key.clone()), move |_| {
+            refresh();
+            || ()
+        });
+    }
+
+    {
+        let refresh = refresh.clone();
+        use_event_with_window(change_event_type(&db.name, &store), move |_: Event| {
+            refresh();
+        });
+    }
+
+    UseIdbValueHandle {
+        inner,
+        loading,
+        error,
+        db: db.db.clone(),
+        store,
+        key,
+        refresh,
+    }
+}
+
+/// A side-effect hook that queries the records of an IndexedDB object store in a key range.
+/// See [`use_idb_query_with_options`] to query an index or limit the number of records.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// struct Document {
+///     title: String,
+/// }
+///
+/// #[function_component(IdbQuery)]
+/// fn idb_query() -> Html {
+///     let db = use_indexed_db("app".to_string(), 1, |upgrade| {
+///         let _ = upgrade.db.create_object_store("documents");
+///     });
+///     let documents = use_idb_query::<Document>(
+///         &db,
+///         "documents".to_string(),
+///         IdbRange::bound("a", "n", false, true),
+///     );
+///
+///     html! {
+///         <ul>
+///             { for documents.iter().map(|document| html! { <li>{ &document.title }</li> }) }
+///         </ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_idb_query<T>(
+    db: &UseIndexedDbHandle,
+    store: String,
+    range: IdbRange,
+) -> UseIdbQueryHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    use_idb_query_with_options(db, store, range, UseIdbQueryOptions::default())
+}
+
+/// A side-effect hook that queries the records of an IndexedDB object store with options.
+/// See [`use_idb_query`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// struct Document {
+///     title: String,
+///     updated_at: u64,
+/// }
+///
+/// #[function_component(IdbQuery)]
+/// fn idb_query() -> Html {
+///     let db = use_indexed_db("app".to_string(), 1, |upgrade| {
+///         if let Ok(store) = upgrade.db.create_object_store("documents") {
+///             let _ = store.create_index_with_str("updated_at", "updated_at");
+///         }
+///     });
+///     let recent = use_idb_query_with_options::<Document>(
+///         &db,
+///         "documents".to_string(),
+///         IdbRange::lower_bound(1_700_000_000, false),
+///         UseIdbQueryOptions {
+///             index: Some("updated_at".to_string()),
+///             limit: Some(10),
+///         },
+///     );
+///
+///     let onclick = {
+///         let recent = recent.clone();
+///         Callback::from(move |_| recent.refresh())
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick} disabled={*recent.loading}>{ "Refresh" }</button>
+///             <ul>
+///                 { for recent.iter().map(|document| html! { <li>{ &document.title }</li> }) }
+///             </ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_idb_query_with_options<T>(
+    db: &UseIndexedDbHandle,
+    store: String,
+    range: IdbRange,
+    options: UseIdbQueryOptions,
+) -> UseIdbQueryHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    let inner: UseStateHandle<Vec<T>> = use_state(Vec::new);
+    let loading = use_state(|| true);
+    let error = use_state(|| None);
+    let version = use_mut_ref(|| 0u32);
+    let query = use_memo((store, range, options), |query| query.clone());
+
+    let refresh: Rc<dyn Fn()> = {
+        let inner = inner.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let db_loading = db.loading;
+        let db = db.db.clone();
+        let query = query.clone();
+        Rc::new(move || {
+            let db = match db.clone() {
+                Some(db) => db,
+                None => {
+                    // Stop loading if the database failed to open.
+                    if !db_loading {
+                        loading.set(false);
+                        error.set(Some(IdbError::NotOpen));
+                    }
+                    return;
+                }
+            };
+            let current = {
+                let mut version = version.borrow_mut();
+                *version = version.wrapping_add(1);
+                *version
+            };
+            if !*loading {
+                loading.set(true);
+            }
+
+            let inner = inner.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let version = version.clone();
+            let query = query.clone();
+            spawn_local(async move {
+                let (store, range, options) = &*query;
+                let result = idb_get_all(&db, store, range, options).await;
+                // Ignore results of outdated queries.
+                if *version.borrow() != current {
+                    return;
+                }
+                match result {
+                    Ok(records) => {
+                        inner.set(records);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let refresh = refresh.clone();
+        use_effect_with((db.db.clone(), query.clone()), move |_| {
+            refresh();
+            || ()
+        });
+    }
+
+    {
+        let refresh = refresh.clone();
+        use_event_with_window(change_event_type(&db.name, &query.0), move |_: Event| {
+            refresh();
+        });
+    }
+
+    UseIdbQueryHandle {
+        inner,
+        loading,
+        error,
+        refresh,
+    }
+}
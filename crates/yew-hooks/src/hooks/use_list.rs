@@ -1,10 +1,12 @@
 use std::cell::{Ref, RefCell};
 use std::cmp::Ordering;
+use std::ops::{Deref, RangeBounds};
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 
-use super::use_update;
+use super::{use_persisted, use_update, Persisted, StorageBackend, StorageWriteError};
 
 /// State handle for the [`use_list`] hook.
 pub struct UseListHandle<T> {
@@ -121,6 +123,100 @@ impl<T> UseListHandle<T> {
         self.inner.borrow_mut().clear();
         (self.update)();
     }
+
+    /// Moves the element at index `from` to index `to`,
+    /// shifting the elements in between, e.g. for drag-to-reorder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn move_item(&self, from: usize, to: usize) {
+        {
+            let mut list = self.inner.borrow_mut();
+            let len = list.len();
+            assert!(
+                from < len && to < len,
+                "move_item index out of bounds: the len is {} but the indexes are {} and {}",
+                len,
+                from,
+                to
+            );
+            if from < to {
+                list[from..=to].rotate_left(1);
+            } else {
+                list[to..=from].rotate_right(1);
+            }
+        }
+        (self.update)();
+    }
+
+    /// Inserts all the `elements` at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` > `len`.
+    pub fn insert_many<I>(&self, index: usize, elements: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.inner.borrow_mut().splice(index..index, elements);
+        (self.update)();
+    }
+
+    /// Removes and returns the elements in the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn remove_range<R>(&self, range: R) -> Vec<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let elements = self.inner.borrow_mut().drain(range).collect();
+        (self.update)();
+        elements
+    }
+
+    /// Update the element at the specified index in place.
+    pub fn update_with<F>(&self, index: usize, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Some(elem) = self.inner.borrow_mut().get_mut(index) {
+            f(elem);
+        }
+        (self.update)();
+    }
+
+    /// Shortens the list, keeping the first `len` elements and dropping the rest.
+    pub fn truncate(&self, len: usize) {
+        self.inner.borrow_mut().truncate(len);
+        (self.update)();
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    pub fn dedup_by_key<F, K>(&self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.inner.borrow_mut().dedup_by_key(key);
+        (self.update)();
+    }
+
+    /// Applies several mutations to the list with a single re-render.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is accessed through the handle inside `f`.
+    pub fn batch<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Vec<T>) -> R,
+    {
+        let result = f(&mut self.inner.borrow_mut());
+        (self.update)();
+        result
+    }
 }
 
 impl<T> Clone for UseListHandle<T> {
@@ -141,6 +237,39 @@ where
     }
 }
 
+/// State handle for the [`use_persisted_list`] hook.
+pub struct UsePersistedListHandle<T> {
+    list: UseListHandle<T>,
+    /// The error of the last failed write to storage, cleared by the next successful write.
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+}
+
+impl<T> Deref for UsePersistedListHandle<T> {
+    type Target = UseListHandle<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T> Clone for UsePersistedListHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UsePersistedListHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.list == other.list
+    }
+}
+
 /// A hook that tracks a list and provides methods to modify it.
 ///
 /// # Example
@@ -209,3 +338,59 @@ where
 
     UseListHandle { inner, update }
 }
+
+/// A hook that tracks a list persisted in a storage backend, e.g. [`LocalStorageBackend`](super::LocalStorageBackend)
+/// or [`SessionStorageBackend`](super::SessionStorageBackend). See [`use_list`] too.
+///
+/// The list is rehydrated from the `key` on mount, falling back to `initial_value`,
+/// written through on every mutation and kept in sync with other hooks and tabs using the same key,
+/// going back to `initial_value` when the key is removed.
+/// When a write fails the collection is kept in memory only and the failure is recorded in `error`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(RecentItems)]
+/// fn recent_items() -> Html {
+///     let recent = use_persisted_list::<LocalStorageBackend, String>("recent".to_string(), vec![]);
+///
+///     let onclick = {
+///         let recent = recent.clone();
+///         Callback::from(move |_| recent.insert(0, "Earth".to_string()))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Visit Earth" }</button>
+///             <p>
+///                 <b>{ "Recent items: " }</b>
+///                 { recent.current().join(", ") }
+///             </p>
+///             if let Some(error) = &*recent.error {
+///                 <p>{ "Not saved: " }{ error.to_string() }</p>
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_list<S, T>(key: String, initial_value: Vec<T>) -> UsePersistedListHandle<T>
+where
+    S: StorageBackend + Default,
+    T: Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    let Persisted {
+        inner,
+        update,
+        error,
+    } = use_persisted::<S, _, _>(key, || initial_value);
+
+    UsePersistedListHandle {
+        list: UseListHandle { inner, update },
+        error,
+    }
+}
@@ -1,62 +1,13 @@
-use std::ops::Deref;
-use std::rc::Rc;
-
-use gloo::storage::{LocalStorage, Storage};
-use serde::{Deserialize, Serialize};
-use web_sys::StorageEvent;
+use serde::Deserialize;
 use yew::prelude::*;
 
-use super::use_event_with_window;
-
-/// State handle for the [`use_local_storage`] hook.
-pub struct UseLocalStorageHandle<T> {
-    inner: UseStateHandle<Option<T>>,
-    key: Rc<String>,
-}
-
-impl<T> UseLocalStorageHandle<T> {
-    /// Set a `value` for the specified key.
-    pub fn set(&self, value: T)
-    where
-        T: Serialize + Clone,
-    {
-        if LocalStorage::set(&*self.key, value.clone()).is_ok() {
-            self.inner.set(Some(value));
-        }
-    }
-
-    /// Delete a key and it's stored value.
-    pub fn delete(&self) {
-        LocalStorage::delete(&*self.key);
-        self.inner.set(None);
-    }
-}
-
-impl<T> Deref for UseLocalStorageHandle<T> {
-    type Target = Option<T>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.inner
-    }
-}
+use super::{use_storage_with_options, LocalStorageBackend, UseStorageHandle, UseStorageOptions};
 
-impl<T> Clone for UseLocalStorageHandle<T> {
-    fn clone(&self) -> Self {
-        Self {
-            inner: self.inner.clone(),
-            key: self.key.clone(),
-        }
-    }
-}
+/// Options for [`use_local_storage_with_options`].
+pub type UseLocalStorageOptions = UseStorageOptions;
 
-impl<T> PartialEq for UseLocalStorageHandle<T>
-where
-    T: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        *self.inner == *other.inner
-    }
-}
+/// State handle for the [`use_local_storage`] hook.
+pub type UseLocalStorageHandle<T> = UseStorageHandle<LocalStorageBackend, T>;
 
 /// A side-effect hook that manages a single localStorage key.
 ///
@@ -103,21 +54,74 @@ pub fn use_local_storage<T>(key: String) -> UseLocalStorageHandle<T>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
-    let inner: UseStateHandle<Option<T>> =
-        use_state(|| LocalStorage::get(&key).unwrap_or_default());
-    let key = use_memo((), |_| key);
-
-    {
-        let key = key.clone();
-        let inner = inner.clone();
-        use_event_with_window("storage", move |e: StorageEvent| {
-            if let Some(k) = e.key() {
-                if k == *key {
-                    inner.set(LocalStorage::get(&*key).unwrap_or_default());
-                }
-            }
-        });
-    }
+    use_local_storage_with_options(key, UseLocalStorageOptions::default())
+}
 
-    UseLocalStorageHandle { inner, key }
+/// A side-effect hook that manages a single localStorage key with options.
+/// See [`use_local_storage`] too.
+///
+/// With [`ttl`](UseLocalStorageOptions::ttl) set, values are stored with an expiry time,
+/// expired values are deleted when read and the component re-renders when the current value expires.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(LocalStorage)]
+/// fn local_storage() -> Html {
+///     let storage = use_local_storage_with_options::<String>(
+///         "foo".to_string(),
+///         UseLocalStorageOptions {
+///             memory_fallback: true,
+///             ttl: Some(Duration::from_secs(60 * 60)),
+///             ..Default::default()
+///         },
+///     );
+///
+///     let onclick = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| {
+///             if let Err(e) = storage.try_set("bar".to_string()) {
+///                 log::warn!("Value is only kept in memory: {}", e);
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Set to bar" }</button>
+///             <p>
+///                 <b>{ "Current value: " }</b>
+///                 {
+///                     if let Some(value) = &*storage {
+///                         html! { value }
+///                     } else {
+///                         html! {}
+///                     }
+///                 }
+///             </p>
+///             {
+///                 if let Some(error) = &*storage.error {
+///                     html! { <p>{ error.to_string() }</p> }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_local_storage_with_options<T>(
+    key: String,
+    options: UseLocalStorageOptions,
+) -> UseLocalStorageHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    use_storage_with_options(LocalStorageBackend, key, options)
 }
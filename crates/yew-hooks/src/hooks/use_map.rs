@@ -1,60 +1,329 @@
 use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
-use std::hash::Hash;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::rc::Rc;
 
+use serde::{Deserialize, Deserializer, Serialize};
 use yew::prelude::*;
 
-use super::use_update;
+use super::{use_persisted, use_update, Persisted, StorageBackend, StorageWriteError};
+
+/// A map that can back the [`use_map`] hook, implemented for [`HashMap`],
+/// [`BTreeMap`] for maps sorted by key, and [`OrderedMap`] for maps in insertion order.
+pub trait MapBackend<K, V> {
+    /// Returns a reference to the value of the key.
+    fn get(&self, k: &K) -> Option<&V>;
+
+    /// Returns a mutable reference to the value of the key.
+    fn get_mut(&mut self, k: &K) -> Option<&mut V>;
+
+    /// Inserts a key-value pair, returning the previous value of the key.
+    fn insert(&mut self, k: K, v: V) -> Option<V>;
+
+    /// Removes a key, returning its value.
+    fn remove(&mut self, k: &K) -> Option<V>;
+
+    /// Retains only the elements specified by the predicate.
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool;
+
+    /// Removes all key-value pairs.
+    fn clear(&mut self);
+}
+
+impl<K, V, S> MapBackend<K, V> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn get(&self, k: &K) -> Option<&V> {
+        HashMap::get(self, k)
+    }
+
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        HashMap::get_mut(self, k)
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        HashMap::insert(self, k, v)
+    }
+
+    fn remove(&mut self, k: &K) -> Option<V> {
+        HashMap::remove(self, k)
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        HashMap::retain(self, f);
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self);
+    }
+}
+
+impl<K, V> MapBackend<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn get(&self, k: &K) -> Option<&V> {
+        BTreeMap::get(self, k)
+    }
+
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        BTreeMap::get_mut(self, k)
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        BTreeMap::insert(self, k, v)
+    }
+
+    fn remove(&mut self, k: &K) -> Option<V> {
+        BTreeMap::remove(self, k)
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        BTreeMap::retain(self, f);
+    }
+
+    fn clear(&mut self) {
+        BTreeMap::clear(self);
+    }
+}
+
+/// A map that iterates in insertion order.
+///
+/// Lookups are linear, so it is meant for the small maps rendered in UIs.
+/// It is serialized as a sequence of key-value pairs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct OrderedMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Creates an empty map.
+    pub const fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Returns the number of key-value pairs.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no key-value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator of the key-value pairs in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// An iterator of the keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// An iterator of the values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Returns `true` if the map contains the key.
+    pub fn contains_key(&self, k: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.entries.iter().any(|(key, _)| key == k)
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> MapBackend<K, V> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    fn get(&self, k: &K) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == k)
+            .map(|(_, v)| v)
+    }
+
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find(|(key, _)| key == k)
+            .map(|(_, v)| v)
+    }
+
+    /// Inserts a key-value pair. An existing key keeps its position.
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        match self.get_mut(&k) {
+            Some(value) => Some(std::mem::replace(value, v)),
+            None => {
+                self.entries.push((k, v));
+                None
+            }
+        }
+    }
+
+    /// Removes a key, shifting the entries after it.
+    fn remove(&mut self, k: &K) -> Option<V> {
+        let index = self.entries.iter().position(|(key, _)| key == k)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+/// Deserialized through [`insert`](MapBackend::insert), so later duplicate keys replace earlier ones.
+impl<'de, K, V> Deserialize<'de> for OrderedMap<K, V>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<(K, V)>::deserialize(deserializer).map(|entries| entries.into_iter().collect())
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    fn from(entries: [(K, V); N]) -> Self {
+        entries.into_iter().collect()
+    }
+}
 
 /// State handle for the [`use_map`] hook.
-pub struct UseMapHandle<K, V> {
-    inner: Rc<RefCell<HashMap<K, V>>>,
+pub struct UseMapHandle<K, V, M = HashMap<K, V>> {
+    inner: Rc<RefCell<M>>,
     update: Rc<dyn Fn()>,
+    _marker: PhantomData<(K, V)>,
 }
 
-impl<K, V> UseMapHandle<K, V> {
+impl<K, V, M> UseMapHandle<K, V, M>
+where
+    M: MapBackend<K, V>,
+{
     /// Get immutable ref to the map.
     ///
     /// # Panics
     ///
     /// Panics if the value is currently mutably borrowed
-    pub fn current(&'_ self) -> Ref<'_, HashMap<K, V>> {
+    pub fn current(&'_ self) -> Ref<'_, M> {
         self.inner.borrow()
     }
 
-    /// Set the hash map.
-    pub fn set(&self, map: HashMap<K, V>) {
+    /// Set the map.
+    pub fn set(&self, map: M) {
         *self.inner.borrow_mut() = map;
         (self.update)();
     }
 
     /// Inserts a key-value pair into the map.
-    pub fn insert(&self, k: K, v: V) -> Option<V>
-    where
-        K: Eq + Hash,
-    {
+    pub fn insert(&self, k: K, v: V) -> Option<V> {
         let v = self.inner.borrow_mut().insert(k, v);
         (self.update)();
         v
     }
 
     /// Update key-value pair.
-    pub fn update(&self, k: &K, v: V)
-    where
-        K: Eq + Hash,
-    {
+    pub fn update(&self, k: &K, v: V) {
         if let Some(value) = self.inner.borrow_mut().get_mut(k) {
             *value = v;
         }
         (self.update)();
     }
 
-    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
-    pub fn remove(&self, k: &K) -> Option<V>
+    /// Update the value of the key in place with `f`,
+    /// inserting the value returned by `default` first if the key is not in the map.
+    pub fn upsert_with<D, F>(&self, k: K, default: D, f: F)
+    where
+        D: FnOnce() -> V,
+        F: FnOnce(&mut V),
+    {
+        {
+            let mut map = self.inner.borrow_mut();
+            match map.get_mut(&k) {
+                Some(value) => f(value),
+                None => {
+                    let mut value = default();
+                    f(&mut value);
+                    map.insert(k, value);
+                }
+            }
+        }
+        (self.update)();
+    }
+
+    /// Inserts all the key-value pairs with a single re-render.
+    pub fn extend<I>(&self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        {
+            let mut map = self.inner.borrow_mut();
+            for (k, v) in iter {
+                map.insert(k, v);
+            }
+        }
+        (self.update)();
+    }
+
+    /// Returns a clone of the value of the key.
+    pub fn get_cloned(&self, k: &K) -> Option<V>
     where
-        K: Eq + Hash,
+        V: Clone,
     {
+        self.inner.borrow().get(k).cloned()
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    pub fn remove(&self, k: &K) -> Option<V> {
         let v = self.inner.borrow_mut().remove(k);
         (self.update)();
         v
@@ -63,40 +332,73 @@ impl<K, V> UseMapHandle<K, V> {
     /// Retains only the elements specified by the predicate.
     pub fn retain<F>(&self, f: F)
     where
-        K: Eq + Hash,
         F: FnMut(&K, &mut V) -> bool,
     {
         self.inner.borrow_mut().retain(f);
         (self.update)();
     }
 
-    /// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
+    /// Clears the map, removing all key-value pairs.
     pub fn clear(&self) {
         self.inner.borrow_mut().clear();
         (self.update)();
     }
 }
 
-impl<K, V> Clone for UseMapHandle<K, V> {
+impl<K, V, M> Clone for UseMapHandle<K, V, M> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
             update: self.update.clone(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<K, V> PartialEq for UseMapHandle<K, V>
+impl<K, V, M> PartialEq for UseMapHandle<K, V, M>
 where
-    K: Eq + Hash,
-    V: PartialEq,
+    M: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         *self.inner == *other.inner
     }
 }
 
+/// State handle for the [`use_persisted_map`] and [`use_persisted_map_with_backend`] hooks.
+pub struct UsePersistedMapHandle<K, V, M = HashMap<K, V>> {
+    map: UseMapHandle<K, V, M>,
+    /// The error of the last failed write to storage, cleared by the next successful write.
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+}
+
+impl<K, V, M> Deref for UsePersistedMapHandle<K, V, M> {
+    type Target = UseMapHandle<K, V, M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<K, V, M> Clone for UsePersistedMapHandle<K, V, M> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<K, V, M> PartialEq for UsePersistedMapHandle<K, V, M>
+where
+    M: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
 /// A hook that tracks a hash map and provides methods to modify it.
+/// See [`use_map_with_backend`] for sorted and insertion-ordered maps.
 ///
 /// # Example
 ///
@@ -175,5 +477,180 @@ where
     let inner = use_mut_ref(|| initial_value);
     let update = use_update();
 
-    UseMapHandle { inner, update }
+    UseMapHandle {
+        inner,
+        update,
+        _marker: PhantomData,
+    }
+}
+
+/// A hook that tracks a map of any [`MapBackend`] and provides methods to modify it.
+///
+/// The map can be a [`HashMap`], a [`BTreeMap`] to render entries sorted by key,
+/// an [`OrderedMap`] to render entries in insertion order, or your own [`MapBackend`].
+///
+/// # Example
+///
+/// Count clicks per button in the order they were first clicked:
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Clicks)]
+/// fn clicks() -> Html {
+///     let clicks = use_map_with_backend(OrderedMap::<&str, u32>::new());
+///
+///     let onclick = |name: &'static str| {
+///         let clicks = clicks.clone();
+///         Callback::from(move |_| clicks.upsert_with(name, || 0, |count| *count += 1))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick("Mercury")}>{ "Mercury" }</button>
+///             <button onclick={onclick("Venus")}>{ "Venus" }</button>
+///             {
+///                 for clicks.current().iter().map(|(k, v)| {
+///                     html! {
+///                         <p><b>{ *k }</b> {": "} { v }</p>
+///                     }
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_map_with_backend<K, V, M>(initial_value: M) -> UseMapHandle<K, V, M>
+where
+    K: 'static,
+    V: 'static,
+    M: MapBackend<K, V> + 'static,
+{
+    let inner = use_mut_ref(|| initial_value);
+    let update = use_update();
+
+    UseMapHandle {
+        inner,
+        update,
+        _marker: PhantomData,
+    }
+}
+
+/// A hook that tracks a hash map persisted in a storage backend, e.g. [`LocalStorageBackend`](super::LocalStorageBackend)
+/// or [`SessionStorageBackend`](super::SessionStorageBackend). See [`use_map`] too.
+///
+/// The map is rehydrated from the `key` on mount, falling back to `initial_value`,
+/// written through on every mutation and kept in sync with other hooks and tabs using the same key,
+/// going back to `initial_value` when the key is removed.
+/// When a write fails the collection is kept in memory only and the failure is recorded in `error`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Ratings)]
+/// fn ratings() -> Html {
+///     let ratings = use_persisted_map::<LocalStorageBackend, String, u32>(
+///         "ratings".to_string(),
+///         HashMap::new(),
+///     );
+///
+///     let onclick = {
+///         let ratings = ratings.clone();
+///         Callback::from(move |_| {
+///             let _ = ratings.insert("Earth".to_string(), 5);
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Rate Earth" }</button>
+///             {
+///                 for ratings.current().iter().map(|(k, v)| {
+///                     html! {
+///                         <p><b>{ k }</b> {": "} { v }</p>
+///                     }
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_map<S, K, V>(
+    key: String,
+    initial_value: HashMap<K, V>,
+) -> UsePersistedMapHandle<K, V>
+where
+    S: StorageBackend + Default,
+    K: Eq + Hash + Serialize + for<'de> Deserialize<'de> + 'static,
+    V: Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    use_persisted_map_with_backend::<S, K, V, _>(key, initial_value)
+}
+
+/// A hook that tracks a map of any [`MapBackend`] persisted in a storage backend.
+/// See [`use_persisted_map`] and [`use_map_with_backend`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Ratings)]
+/// fn ratings() -> Html {
+///     // Rendered sorted by name.
+///     let ratings = use_persisted_map_with_backend::<LocalStorageBackend, String, u32, _>(
+///         "ratings".to_string(),
+///         BTreeMap::new(),
+///     );
+///
+///     html! {
+///         <div>
+///             {
+///                 for ratings.current().iter().map(|(k, v)| {
+///                     html! {
+///                         <p><b>{ k }</b> {": "} { v }</p>
+///                     }
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_map_with_backend<S, K, V, M>(
+    key: String,
+    initial_value: M,
+) -> UsePersistedMapHandle<K, V, M>
+where
+    S: StorageBackend + Default,
+    K: 'static,
+    V: 'static,
+    M: MapBackend<K, V> + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    let Persisted {
+        inner,
+        update,
+        error,
+    } = use_persisted::<S, _, _>(key, || initial_value);
+
+    UsePersistedMapHandle {
+        map: UseMapHandle {
+            inner,
+            update,
+            _marker: PhantomData,
+        },
+        error,
+    }
 }
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::use_cmp_version;
+
+/// This hook is similar to [`use_memo`] but compares dependencies with `eq`
+/// instead of `PartialEq`. The value is recomputed whenever `eq` returns `false`
+/// for the previous and new dependencies.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Properties)]
+/// struct Props {
+///     items: Rc<Vec<u32>>,
+/// }
+///
+/// impl PartialEq for Props {
+///     fn eq(&self, other: &Self) -> bool {
+///         Rc::ptr_eq(&self.items, &other.items)
+///     }
+/// }
+///
+/// #[function_component(UseMemoWithCmp)]
+/// fn memo_with_cmp(props: &Props) -> Html {
+///     // Only sums again when given another list, without comparing every item.
+///     let total = use_memo_with_cmp(props.items.clone(), Rc::ptr_eq, |items| {
+///         items.iter().sum::<u32>()
+///     });
+///
+///     html! {
+///         <p>{ "Total: " }{ *total }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_memo_with_cmp<T, F, Dependents, Eq>(deps: Dependents, eq: Eq, f: F) -> Rc<T>
+where
+    T: 'static,
+    F: FnOnce(&Dependents) -> T,
+    Dependents: 'static,
+    Eq: Fn(&Dependents, &Dependents) -> bool,
+{
+    let (version, deps) = use_cmp_version(deps, eq);
+
+    use_memo(version, move |_| f(&deps))
+}
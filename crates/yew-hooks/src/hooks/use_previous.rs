@@ -1,8 +1,27 @@
+use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
 use yew::prelude::*;
 
+/// A value recorded by [`use_previous_distinct`] or [`use_history_of`](super::use_history_of).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampedValue<T> {
+    /// The value.
+    pub value: T,
+    /// When the value was recorded, in milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+impl<T> TimestampedValue<T> {
+    pub(crate) fn now(value: T) -> Self {
+        Self {
+            value,
+            timestamp: js_sys::Date::now(),
+        }
+    }
+}
+
 /// State handle for the [`use_previous`] hook.
 pub struct UsePreviousHandle<T> {
     inner: Rc<T>,
@@ -93,3 +112,116 @@ where
 
     UsePreviousHandle { inner }
 }
+
+/// State handle for the [`use_previous_distinct`] hook.
+pub struct UsePreviousDistinctHandle<T> {
+    previous: Option<Rc<TimestampedValue<T>>>,
+    current: Rc<TimestampedValue<T>>,
+}
+
+impl<T> UsePreviousDistinctHandle<T> {
+    /// The value before the last change, `None` if the value never changed.
+    pub fn previous(&self) -> Option<&TimestampedValue<T>> {
+        self.previous.as_deref()
+    }
+
+    /// The current value, recorded when it last changed.
+    pub fn current(&self) -> &TimestampedValue<T> {
+        &self.current
+    }
+}
+
+impl<T> Clone for UsePreviousDistinctHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            previous: self.previous.clone(),
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UsePreviousDistinctHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.previous == other.previous && self.current == other.current
+    }
+}
+
+impl<T> fmt::Debug for UsePreviousDistinctHandle<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsePreviousDistinctHandle")
+            .field("previous", &self.previous)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+/// This hook returns the previous distinct value of state or props,
+/// only advancing when `eq` returns `false` for the last and the new value.
+/// See [`use_previous`] to advance on every render.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_sys::HtmlInputElement;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(PreviousQuery)]
+/// fn previous_query() -> Html {
+///     let query = use_state(|| "".to_string());
+///     // Ignore changes in case or surrounding whitespace.
+///     let previous = use_previous_distinct((*query).clone(), |a: &String, b: &String| {
+///         a.trim().eq_ignore_ascii_case(b.trim())
+///     });
+///
+///     let oninput = {
+///         let query = query.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let input: HtmlInputElement = e.target_unchecked_into();
+///             query.set(input.value());
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <input value={(*query).clone()} {oninput} />
+///             <p>
+///                 <b>{ "Previous query: " }</b>
+///                 { previous.previous().map(|p| p.value.clone()).unwrap_or_default() }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_previous_distinct<T, F>(value: T, eq: F) -> UsePreviousDistinctHandle<T>
+where
+    T: 'static,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut value = Some(value);
+    let state = use_mut_ref(|| UsePreviousDistinctHandle {
+        previous: None,
+        current: Rc::new(TimestampedValue::now(value.take().unwrap())),
+    });
+
+    // The value is only left after the first render.
+    if let Some(value) = value {
+        let mut state = state.borrow_mut();
+        if !eq(&state.current.value, &value) {
+            let current =
+                std::mem::replace(&mut state.current, Rc::new(TimestampedValue::now(value)));
+            state.previous = Some(current);
+        }
+    }
+
+    let handle = state.borrow().clone();
+    handle
+}
@@ -0,0 +1,267 @@
+use std::cell::{Ref, RefCell};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::use_update;
+
+type Compare<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// An element ordered by the comparator of its queue.
+struct Entry<T> {
+    value: T,
+    compare: Compare<T>,
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(&self.value, &other.value)
+    }
+}
+
+/// State handle for the [`use_priority_queue`] hook.
+pub struct UsePriorityQueueHandle<T> {
+    inner: Rc<RefCell<BinaryHeap<Entry<T>>>>,
+    compare: Compare<T>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<T> UsePriorityQueueHandle<T> {
+    fn entry(&self, value: T) -> Entry<T> {
+        Entry {
+            value,
+            compare: self.compare.clone(),
+        }
+    }
+
+    /// Set the elements of the queue.
+    pub fn set(&self, values: Vec<T>) {
+        *self.inner.borrow_mut() = values.into_iter().map(|value| self.entry(value)).collect();
+        (self.update)();
+    }
+
+    /// Pushes an element onto the queue.
+    pub fn push(&self, value: T) {
+        let entry = self.entry(value);
+        self.inner.borrow_mut().push(entry);
+        (self.update)();
+    }
+
+    /// Removes the element with the highest priority and returns it, or None if the queue is empty.
+    pub fn pop(&self) -> Option<T> {
+        let value = self.inner.borrow_mut().pop().map(|entry| entry.value);
+        (self.update)();
+        value
+    }
+
+    /// Get immutable ref to the element with the highest priority, or None if the queue is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed
+    pub fn peek(&'_ self) -> Option<Ref<'_, T>> {
+        Ref::filter_map(self.inner.borrow(), |heap| {
+            heap.peek().map(|entry| &entry.value)
+        })
+        .ok()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+
+    /// Returns the elements from the highest to the lowest priority.
+    pub fn sorted(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut values = self
+            .inner
+            .borrow()
+            .iter()
+            .map(|entry| entry.value.clone())
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| (self.compare)(b, a));
+        values
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner.borrow_mut().retain(|entry| f(&entry.value));
+        (self.update)();
+    }
+
+    /// Clears the queue, removing all values.
+    pub fn clear(&self) {
+        self.inner.borrow_mut().clear();
+        (self.update)();
+    }
+}
+
+impl<T> Clone for UsePriorityQueueHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            compare: self.compare.clone(),
+            update: self.update.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UsePriorityQueueHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let heap = self.inner.borrow();
+        let other = other.inner.borrow();
+        heap.len() == other.len()
+            && heap
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.value == b.value)
+    }
+}
+
+/// A hook that tracks a priority queue, popping the greatest element first.
+/// See [`use_priority_queue_by`] for a custom ordering.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Jobs)]
+/// fn jobs() -> Html {
+///     // (priority, name)
+///     let jobs = use_priority_queue(vec![(1, "Backup"), (5, "Deploy"), (3, "Test")]);
+///
+///     let onpush = {
+///         let jobs = jobs.clone();
+///         Callback::from(move |_| jobs.push((4, "Build")))
+///     };
+///     let onpop = {
+///         let jobs = jobs.clone();
+///         Callback::from(move |_| {
+///             let _ = jobs.pop();
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onpush}>{ "Push" }</button>
+///             <button onclick={onpop}>{ "Run next job" }</button>
+///             <p>
+///                 <b>{ "Next job: " }</b>
+///                 { jobs.peek().map(|job| job.1).unwrap_or("None") }
+///             </p>
+///             { for jobs.sorted().iter().map(|(_, name)| html! { <p>{ *name }</p> }) }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_priority_queue<T>(initial_value: Vec<T>) -> UsePriorityQueueHandle<T>
+where
+    T: Ord + 'static,
+{
+    use_priority_queue_by(initial_value, T::cmp)
+}
+
+/// A hook that tracks a priority queue with a custom ordering, popping the greatest element first
+/// according to `compare`. See [`use_priority_queue`] too.
+///
+/// The `compare` function is only read on mount.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Toast {
+///     message: String,
+///     urgent: bool,
+///     created_at: u32,
+/// }
+///
+/// #[function_component(Toasts)]
+/// fn toasts() -> Html {
+///     // Urgent toasts first, then the oldest ones.
+///     let toasts = use_priority_queue_by(vec![], |a: &Toast, b: &Toast| {
+///         a.urgent.cmp(&b.urgent).then(b.created_at.cmp(&a.created_at))
+///     });
+///
+///     let ondismiss = {
+///         let toasts = toasts.clone();
+///         Callback::from(move |_| {
+///             let _ = toasts.pop();
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             if let Some(toast) = toasts.peek() {
+///                 <p>{ &toast.message }</p>
+///             }
+///             <button onclick={ondismiss}>{ "Dismiss" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_priority_queue_by<T, F>(initial_value: Vec<T>, compare: F) -> UsePriorityQueueHandle<T>
+where
+    T: 'static,
+    F: Fn(&T, &T) -> Ordering + 'static,
+{
+    let compare = use_memo((), move |_| -> Compare<T> { Rc::new(compare) });
+    let inner = {
+        let compare = (*compare).clone();
+        use_mut_ref(move || {
+            initial_value
+                .into_iter()
+                .map(|value| Entry {
+                    value,
+                    compare: compare.clone(),
+                })
+                .collect()
+        })
+    };
+    let update = use_update();
+
+    UsePriorityQueueHandle {
+        inner,
+        compare: (*compare).clone(),
+        update,
+    }
+}
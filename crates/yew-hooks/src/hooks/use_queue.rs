@@ -1,16 +1,42 @@
 use std::cell::{Ref, RefCell};
 use std::collections::VecDeque;
 use std::hash::Hash;
+use std::ops::Deref;
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 
-use super::use_update;
+use super::{use_persisted, use_update, Persisted, StorageBackend, StorageWriteError};
+
+/// Options for [`use_queue_with_options`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UseQueueOptions {
+    /// Maximum number of elements, the queue then acts as a ring buffer
+    /// dropping the oldest elements on overflow. Unbounded if `None`.
+    pub capacity: Option<usize>,
+}
+
+impl UseQueueOptions {
+    /// A ring buffer of at most `capacity` elements.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+        }
+    }
+}
+
+/// Drop the oldest elements at the front beyond `capacity`.
+fn drop_overflow<T>(queue: &mut VecDeque<T>, capacity: usize) {
+    let overflow = queue.len().saturating_sub(capacity);
+    queue.drain(..overflow);
+}
 
 /// State handle for the [`use_queue`] hook.
 pub struct UseQueueHandle<T> {
     inner: Rc<RefCell<VecDeque<T>>>,
     update: Rc<dyn Fn()>,
+    capacity: Option<usize>,
 }
 
 impl<T> UseQueueHandle<T> {
@@ -23,18 +49,41 @@ impl<T> UseQueueHandle<T> {
         self.inner.borrow()
     }
 
-    /// Set the queue.
-    pub fn set(&self, queue: VecDeque<T>) {
+    /// Set the queue, dropping the elements at the front that overflow a bounded queue.
+    pub fn set(&self, mut queue: VecDeque<T>) {
+        if let Some(capacity) = self.capacity {
+            drop_overflow(&mut queue, capacity);
+        }
         *self.inner.borrow_mut() = queue;
         (self.update)();
     }
 
     /// Appends an element to the back of the queue.
+    /// If the queue is full, the element at the front is dropped.
     pub fn push_back(&self, value: T)
     where
         T: Eq + Hash,
     {
-        self.inner.borrow_mut().push_back(value);
+        {
+            let mut queue = self.inner.borrow_mut();
+            queue.push_back(value);
+            if let Some(capacity) = self.capacity {
+                drop_overflow(&mut queue, capacity);
+            }
+        }
+        (self.update)();
+    }
+
+    /// Prepends an element to the front of the queue.
+    /// If the queue is full, the element at the back is dropped.
+    pub fn push_front(&self, value: T) {
+        {
+            let mut queue = self.inner.borrow_mut();
+            queue.push_front(value);
+            if let Some(capacity) = self.capacity {
+                queue.truncate(capacity);
+            }
+        }
         (self.update)();
     }
 
@@ -48,6 +97,38 @@ impl<T> UseQueueHandle<T> {
         v
     }
 
+    /// Removes the last element and returns it, or None if the queue is empty.
+    pub fn pop_back(&self) -> Option<T> {
+        let v = self.inner.borrow_mut().pop_back();
+        (self.update)();
+        v
+    }
+
+    /// Get immutable ref to the first element, or None if the queue is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed
+    pub fn peek(&'_ self) -> Option<Ref<'_, T>> {
+        Ref::filter_map(self.inner.borrow(), |queue| queue.front()).ok()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+
+    /// Returns `true` if the queue is bounded and holds `capacity` elements.
+    pub fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.inner.borrow().len() >= capacity)
+    }
+
     /// Retains only the elements specified by the predicate.
     pub fn retain<F>(&self, f: F)
     where
@@ -70,6 +151,7 @@ impl<T> Clone for UseQueueHandle<T> {
         Self {
             inner: self.inner.clone(),
             update: self.update.clone(),
+            capacity: self.capacity,
         }
     }
 }
@@ -83,6 +165,39 @@ where
     }
 }
 
+/// State handle for the [`use_persisted_queue`] hook.
+pub struct UsePersistedQueueHandle<T> {
+    queue: UseQueueHandle<T>,
+    /// The error of the last failed write to storage, cleared by the next successful write.
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+}
+
+impl<T> Deref for UsePersistedQueueHandle<T> {
+    type Target = UseQueueHandle<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.queue
+    }
+}
+
+impl<T> Clone for UsePersistedQueueHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UsePersistedQueueHandle<T>
+where
+    T: Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.queue == other.queue
+    }
+}
+
 /// A hook that tracks a queue and provides methods to modify it.
 ///
 /// # Example
@@ -148,8 +263,137 @@ pub fn use_queue<T>(initial_value: VecDeque<T>) -> UseQueueHandle<T>
 where
     T: 'static,
 {
-    let inner = use_mut_ref(|| initial_value);
+    use_queue_with_options(initial_value, UseQueueOptions::default())
+}
+
+/// A hook that tracks a queue with options, e.g. a bounded ring buffer. See [`use_queue`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(RecentEvents)]
+/// fn recent_events() -> Html {
+///     // Keeps the 3 most recent events.
+///     let events = use_queue_with_options(VecDeque::new(), UseQueueOptions::with_capacity(3));
+///     let counter = use_counter(0);
+///
+///     let onclick = {
+///         let events = events.clone();
+///         let counter = counter.clone();
+///         Callback::from(move |_| {
+///             counter.increase();
+///             events.push_back(format!("Event {}", *counter + 1));
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "Add event" }</button>
+///             <p>
+///                 <b>{ "Oldest event: " }</b>
+///                 { events.peek().map(|event| event.clone()).unwrap_or_default() }
+///             </p>
+///             { for events.current().iter().map(|event| html! { <p>{ event }</p> }) }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_queue_with_options<T>(
+    initial_value: VecDeque<T>,
+    options: UseQueueOptions,
+) -> UseQueueHandle<T>
+where
+    T: 'static,
+{
+    let inner = use_mut_ref(|| {
+        let mut queue = initial_value;
+        if let Some(capacity) = options.capacity {
+            drop_overflow(&mut queue, capacity);
+        }
+        queue
+    });
     let update = use_update();
 
-    UseQueueHandle { inner, update }
+    UseQueueHandle {
+        inner,
+        update,
+        capacity: options.capacity,
+    }
+}
+
+/// A hook that tracks a queue persisted in a storage backend, e.g. [`LocalStorageBackend`](super::LocalStorageBackend)
+/// or [`SessionStorageBackend`](super::SessionStorageBackend). See [`use_queue`] too.
+///
+/// The queue is rehydrated from the `key` on mount, falling back to `initial_value`,
+/// written through on every mutation and kept in sync with other hooks and tabs using the same key,
+/// going back to `initial_value` when the key is removed.
+/// When a write fails the collection is kept in memory only and the failure is recorded in `error`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Jobs)]
+/// fn jobs() -> Html {
+///     let jobs = use_persisted_queue::<SessionStorageBackend, String>(
+///         "jobs".to_string(),
+///         VecDeque::new(),
+///     );
+///
+///     let onpush = {
+///         let jobs = jobs.clone();
+///         Callback::from(move |_| jobs.push_back("Upload".to_string()))
+///     };
+///     let onpop = {
+///         let jobs = jobs.clone();
+///         Callback::from(move |_| {
+///             let _ = jobs.pop_front();
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onpush}>{ "Push back" }</button>
+///             <button onclick={onpop}>{ "Pop front" }</button>
+///             <p>
+///                 <b>{ "Pending jobs: " }</b>
+///                 { jobs.current().len() }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_queue<S, T>(
+    key: String,
+    initial_value: VecDeque<T>,
+) -> UsePersistedQueueHandle<T>
+where
+    S: StorageBackend + Default,
+    T: Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    let Persisted {
+        inner,
+        update,
+        error,
+    } = use_persisted::<S, _, _>(key, || initial_value);
+
+    UsePersistedQueueHandle {
+        queue: UseQueueHandle {
+            inner,
+            update,
+            capacity: None,
+        },
+        error,
+    }
 }
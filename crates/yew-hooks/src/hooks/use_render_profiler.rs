@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use js_sys::{Function, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Performance, PerformanceEntry};
+use yew::prelude::*;
+
+use super::use_mut_latest;
+
+/// Rolling render duration stats of [`use_render_profiler`], in milliseconds.
+/// `mean`, `p95` and `max` are over the last `window` renders.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RenderStats {
+    /// Renders measured since mount or the last reset.
+    pub count: u32,
+    /// Duration of the last render.
+    pub last: f64,
+    /// Mean duration.
+    pub mean: f64,
+    /// 95th percentile duration.
+    pub p95: f64,
+    /// Longest duration.
+    pub max: f64,
+}
+
+impl RenderStats {
+    fn to_js(self) -> JsValue {
+        let object = Object::new();
+        for (key, value) in [
+            ("count", f64::from(self.count)),
+            ("last", self.last),
+            ("mean", self.mean),
+            ("p95", self.p95),
+            ("max", self.max),
+        ] {
+            let _ = Reflect::set(&object, &key.into(), &value.into());
+        }
+        object.into()
+    }
+}
+
+/// Options for [`use_render_profiler_with_options`].
+pub struct UseRenderProfilerOptions {
+    /// Callback with the stats after every `every` renders.
+    pub onstats: Option<Box<dyn FnMut(RenderStats)>>,
+    /// Print the stats with `console.table` after every `every` renders.
+    pub console_table: bool,
+    /// How many renders to report after. Defaults to every render.
+    pub every: u32,
+    /// How many of the last renders the stats are over. Defaults to 100.
+    pub window: usize,
+    /// Keep a measure named `name` for every render in the browser's performance timeline.
+    /// Off by default, as the entries pile up in the User Timing buffer.
+    pub keep_measures: bool,
+}
+
+impl Default for UseRenderProfilerOptions {
+    fn default() -> Self {
+        Self {
+            onstats: None,
+            console_table: false,
+            every: 1,
+            window: 100,
+            keep_measures: false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Profile {
+    count: u32,
+    samples: VecDeque<f64>,
+}
+
+impl Profile {
+    fn record(&mut self, duration: f64, window: usize) {
+        self.count = self.count.saturating_add(1);
+        self.samples.push_back(duration);
+        while self.samples.len() > window.max(1) {
+            self.samples.pop_front();
+        }
+    }
+
+    fn stats(&self) -> RenderStats {
+        if self.samples.is_empty() {
+            return RenderStats::default();
+        }
+
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let p95 = (sorted.len() * 95).div_ceil(100).max(1) - 1;
+
+        RenderStats {
+            count: self.count,
+            last: self.samples.back().copied().unwrap_or_default(),
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            p95: sorted[p95],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Calls `performance.measure` and returns the measure it creates.
+/// `web_sys` only binds the return value behind `web_sys_unstable_apis`,
+/// and older browsers return `undefined`.
+fn measure(performance: &Performance, name: &str, start_mark: &str) -> Option<PerformanceEntry> {
+    Reflect::get(performance, &"measure".into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?
+        .call2(performance, &name.into(), &start_mark.into())
+        .ok()?
+        .dyn_into::<PerformanceEntry>()
+        .ok()
+}
+
+/// State handle for the [`use_render_profiler`] hook.
+pub struct UseRenderProfilerHandle {
+    inner: Rc<RefCell<Profile>>,
+}
+
+impl UseRenderProfilerHandle {
+    /// Get the stats so far. This does not re-render the component when stats change.
+    pub fn stats(&self) -> RenderStats {
+        self.inner.borrow().stats()
+    }
+
+    /// Clear the stats.
+    pub fn reset(&self) {
+        *self.inner.borrow_mut() = Profile::default();
+    }
+}
+
+impl Clone for UseRenderProfilerHandle {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl PartialEq for UseRenderProfilerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+/// A hook that profiles how long component renders take, from render to effects,
+/// with `performance.mark` and `performance.measure`.
+/// The measures are cleared once read, set [`keep_measures`](UseRenderProfilerOptions::keep_measures)
+/// to keep renders in the browser's performance timeline as `name`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(RenderProfiler)]
+/// fn render_profiler() -> Html {
+///     let profiler = use_render_profiler("RenderProfiler".to_string());
+///     let update = use_update();
+///
+///     let onclick = Callback::from(move |_| update());
+///     let stats = profiler.stats();
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Update" }</button>
+///             <p>{ format!("{} renders, mean {:.2}ms, p95 {:.2}ms", stats.count, stats.mean, stats.p95) }</p>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_render_profiler(name: String) -> UseRenderProfilerHandle {
+    use_render_profiler_with_options(name, UseRenderProfilerOptions::default())
+}
+
+/// A hook that profiles how long component renders take and reports the stats
+/// to a callback or the console with [`UseRenderProfilerOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// # use log::debug;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(RenderProfiler)]
+/// fn render_profiler() -> Html {
+///     use_render_profiler_with_options(
+///         "RenderProfiler".to_string(),
+///         UseRenderProfilerOptions {
+///             onstats: Some(Box::new(|stats| {
+///                 debug!("max render {:.2}ms", stats.max);
+///             })),
+///             // Also print a table in the console every 10 renders.
+///             console_table: true,
+///             every: 10,
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <p>{ "Profiled" }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_render_profiler_with_options(
+    name: String,
+    options: UseRenderProfilerOptions,
+) -> UseRenderProfilerHandle {
+    let inner = use_mut_ref(Profile::default);
+    let options = use_mut_latest(options);
+
+    let performance = web_sys::window().and_then(|window| window.performance());
+    // Tell marks of several instances with the same name apart.
+    let start_mark = format!("{} render {:p}", name, Rc::as_ptr(&inner));
+    let start = performance.as_ref().map(|performance| {
+        let _ = performance.mark(&start_mark);
+        performance.now()
+    });
+
+    {
+        let inner = inner.clone();
+        use_effect(move || {
+            if let (Some(performance), Some(start)) = (performance, start) {
+                let options = options.current();
+                let options = &mut *options.borrow_mut();
+
+                // Measures to be cleared get the unique mark name,
+                // so kept measures of other instances named `name` are not cleared.
+                let measure_name = if options.keep_measures {
+                    &name
+                } else {
+                    &start_mark
+                };
+                let duration = measure(&performance, measure_name, &start_mark)
+                    .map(|entry| entry.duration())
+                    .unwrap_or_else(|| performance.now() - start);
+                performance.clear_marks_with_mark_name(&start_mark);
+                if !options.keep_measures {
+                    performance.clear_measures_with_measure_name(measure_name);
+                }
+
+                let stats = {
+                    let mut profile = inner.borrow_mut();
+                    profile.record(duration, options.window);
+                    profile.stats()
+                };
+
+                if stats.count % options.every.max(1) == 0 {
+                    if let Some(onstats) = &mut options.onstats {
+                        onstats(stats);
+                    }
+                    if options.console_table {
+                        let table = Object::new();
+                        let _ = Reflect::set(&table, &name.as_str().into(), &stats.to_js());
+                        gloo::console::table!(table);
+                    }
+                }
+            }
+
+            || ()
+        });
+    }
+
+    UseRenderProfilerHandle { inner }
+}
@@ -0,0 +1,491 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::use_update;
+
+/// Whether one or several keys can be selected with [`use_selection_with_options`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// At most one key is selected.
+    Single,
+    /// Any number of keys is selected.
+    #[default]
+    Multiple,
+}
+
+/// Options for [`use_selection_with_options`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UseSelectionOptions {
+    /// Whether one or several keys can be selected. Defaults to multiple.
+    pub mode: SelectionMode,
+}
+
+impl UseSelectionOptions {
+    /// Select at most one key.
+    pub const fn single() -> Self {
+        Self {
+            mode: SelectionMode::Single,
+        }
+    }
+}
+
+struct SelectionState<K> {
+    /// The keys without duplicates.
+    keys: Vec<K>,
+    /// The index of each key in `keys`.
+    indexes: HashMap<K, usize>,
+    /// Always a subset of `keys`.
+    selected: HashSet<K>,
+    /// The key ranges are selected from.
+    anchor: Option<K>,
+    /// The key moved with the keyboard.
+    focused: Option<K>,
+    mode: SelectionMode,
+}
+
+impl<K> SelectionState<K>
+where
+    K: Clone + Eq + Hash,
+{
+    fn index(&self, key: &K) -> Option<usize> {
+        self.indexes.get(key).copied()
+    }
+
+    /// Keys not in `keys` are ignored.
+    fn select(&mut self, key: K) {
+        if !self.indexes.contains_key(&key) {
+            return;
+        }
+        self.selected.clear();
+        self.selected.insert(key.clone());
+        self.anchor = Some(key.clone());
+        self.focused = Some(key);
+    }
+
+    /// Keys not in `keys` are ignored.
+    fn toggle(&mut self, key: K) {
+        if !self.indexes.contains_key(&key) {
+            return;
+        }
+        if self.selected.contains(&key) {
+            self.selected.remove(&key);
+        } else {
+            if self.mode == SelectionMode::Single {
+                self.selected.clear();
+            }
+            self.selected.insert(key.clone());
+        }
+        self.anchor = Some(key.clone());
+        self.focused = Some(key);
+    }
+
+    /// Select the keys between the anchor and `key`, added to the selection if `additive`.
+    fn select_range(&mut self, key: K, additive: bool) {
+        let range = match (
+            self.anchor.as_ref().and_then(|a| self.index(a)),
+            self.index(&key),
+        ) {
+            (Some(anchor), Some(index)) if self.mode == SelectionMode::Multiple => {
+                anchor.min(index)..=anchor.max(index)
+            }
+            _ => return self.select(key),
+        };
+        if !additive {
+            self.selected.clear();
+        }
+        self.selected.extend(self.keys[range].iter().cloned());
+        self.focused = Some(key);
+    }
+
+    /// Move the focus by `offset` keys, extending the selection from the anchor if `extend`.
+    fn move_focus(&mut self, offset: isize, extend: bool) {
+        let last = match self.keys.len().checked_sub(1) {
+            Some(last) => last,
+            None => return,
+        };
+        let index = match self.focused.as_ref().and_then(|key| self.index(key)) {
+            Some(index) => index.saturating_add_signed(offset).min(last),
+            None if offset > 0 => 0,
+            None => last,
+        };
+        self.focus_index(index, extend);
+    }
+
+    /// Focus the key at `index`, extending the selection from the anchor if `extend`.
+    fn focus_index(&mut self, index: usize, extend: bool) {
+        let key = match self.keys.get(index) {
+            Some(key) => key.clone(),
+            None => return,
+        };
+
+        if self.mode == SelectionMode::Single {
+            self.select(key);
+        } else if extend {
+            if self.anchor.is_none() {
+                self.anchor = self.focused.clone().or_else(|| Some(key.clone()));
+            }
+            self.select_range(key, false);
+        } else {
+            self.focused = Some(key);
+        }
+    }
+
+    /// Set the keys without their duplicates,
+    /// dropping the selected, anchor and focused keys no longer in `keys`.
+    fn set_keys(&mut self, keys: Vec<K>) {
+        let mut indexes = HashMap::with_capacity(keys.len());
+        self.keys = keys
+            .into_iter()
+            .filter(|key| {
+                if indexes.contains_key(key) {
+                    return false;
+                }
+                indexes.insert(key.clone(), indexes.len());
+                true
+            })
+            .collect();
+        self.indexes = indexes;
+
+        self.selected.retain(|key| self.indexes.contains_key(key));
+        if self
+            .anchor
+            .as_ref()
+            .is_some_and(|key| !self.indexes.contains_key(key))
+        {
+            self.anchor = None;
+        }
+        if self
+            .focused
+            .as_ref()
+            .is_some_and(|key| !self.indexes.contains_key(key))
+        {
+            self.focused = None;
+        }
+    }
+}
+
+/// State handle for the [`use_selection`] hook.
+pub struct UseSelectionHandle<K> {
+    inner: Rc<RefCell<SelectionState<K>>>,
+    update: Rc<dyn Fn()>,
+}
+
+impl<K> UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    /// Returns `true` if the key is selected.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.inner.borrow().selected.contains(key)
+    }
+
+    /// The selected keys, in the order of the keys.
+    pub fn selected(&self) -> Vec<K> {
+        let state = self.inner.borrow();
+        state
+            .keys
+            .iter()
+            .filter(|key| state.selected.contains(key))
+            .cloned()
+            .collect()
+    }
+
+    /// Number of selected keys.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().selected.len()
+    }
+
+    /// Returns `true` if no key is selected.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().selected.is_empty()
+    }
+
+    /// Returns `true` if there are keys and all are selected, e.g. for a header checkbox.
+    pub fn is_all_selected(&self) -> bool {
+        let state = self.inner.borrow();
+        !state.keys.is_empty() && state.selected.len() == state.keys.len()
+    }
+
+    /// Returns `true` if some but not all keys are selected,
+    /// e.g. for the `indeterminate` state of a header checkbox.
+    pub fn is_indeterminate(&self) -> bool {
+        let state = self.inner.borrow();
+        !state.selected.is_empty() && state.selected.len() < state.keys.len()
+    }
+
+    /// The key ranges are selected from, the last key clicked or toggled.
+    pub fn anchor(&self) -> Option<K> {
+        self.inner.borrow().anchor.clone()
+    }
+
+    /// The key moved with the keyboard.
+    pub fn focused(&self) -> Option<K> {
+        self.inner.borrow().focused.clone()
+    }
+
+    /// Select only the key, e.g. on a plain click.
+    pub fn select(&self, key: K) {
+        self.inner.borrow_mut().select(key);
+        (self.update)();
+    }
+
+    /// Toggle the key, keeping the rest of the selection, e.g. for a checkbox or a ctrl-click.
+    pub fn toggle(&self, key: K) {
+        self.inner.borrow_mut().toggle(key);
+        (self.update)();
+    }
+
+    /// Select only the keys between the anchor and the key, e.g. on a shift-click.
+    /// Selects only the key if there is no anchor.
+    pub fn select_range(&self, key: K) {
+        self.inner.borrow_mut().select_range(key, false);
+        (self.update)();
+    }
+
+    /// Handle a click on the key: shift selects a range from the anchor,
+    /// ctrl or meta toggles the key, both add the range to the selection.
+    pub fn click(&self, key: K, e: &MouseEvent) {
+        {
+            let mut state = self.inner.borrow_mut();
+            let toggle = e.ctrl_key() || e.meta_key();
+            match (e.shift_key(), toggle) {
+                (true, additive) => state.select_range(key, additive),
+                (false, true) => state.toggle(key),
+                (false, false) => state.select(key),
+            }
+        }
+        (self.update)();
+    }
+
+    /// Select all keys. Ignored in single mode.
+    pub fn select_all(&self) {
+        {
+            let mut state = self.inner.borrow_mut();
+            if state.mode == SelectionMode::Single {
+                return;
+            }
+            state.selected = state.keys.iter().cloned().collect();
+        }
+        (self.update)();
+    }
+
+    /// Clear the selection.
+    pub fn select_none(&self) {
+        {
+            let mut state = self.inner.borrow_mut();
+            state.selected.clear();
+            state.anchor = None;
+        }
+        (self.update)();
+    }
+
+    /// Select the keys not selected and unselect the others. Ignored in single mode.
+    pub fn invert(&self) {
+        {
+            let mut state = self.inner.borrow_mut();
+            if state.mode == SelectionMode::Single {
+                return;
+            }
+            state.selected = state
+                .keys
+                .iter()
+                .filter(|key| !state.selected.contains(key))
+                .cloned()
+                .collect();
+        }
+        (self.update)();
+    }
+
+    /// Select all keys, or none if all are selected, e.g. for a header checkbox.
+    pub fn toggle_all(&self) {
+        if self.is_all_selected() {
+            self.select_none();
+        } else {
+            self.select_all();
+        }
+    }
+
+    /// Handle a keydown on the list or table:
+    /// arrow up/down, home and end move the focus, with shift to extend the selection,
+    /// space toggles the focused key, ctrl or meta + a selects all and escape clears the selection.
+    /// In single mode the selection follows the focus.
+    pub fn handle_keydown(&self, e: &KeyboardEvent) {
+        {
+            let mut state = self.inner.borrow_mut();
+            match e.key().as_str() {
+                "ArrowDown" => state.move_focus(1, e.shift_key()),
+                "ArrowUp" => state.move_focus(-1, e.shift_key()),
+                "Home" => state.focus_index(0, e.shift_key()),
+                "End" => {
+                    let last = state.keys.len().saturating_sub(1);
+                    state.focus_index(last, e.shift_key());
+                }
+                " " => match state.focused.clone() {
+                    Some(key) => state.toggle(key),
+                    None => return,
+                },
+                "a" | "A"
+                    if (e.ctrl_key() || e.meta_key()) && state.mode == SelectionMode::Multiple =>
+                {
+                    state.selected = state.keys.iter().cloned().collect();
+                }
+                "Escape" => {
+                    state.selected.clear();
+                    state.anchor = None;
+                }
+                _ => return,
+            }
+        }
+        e.prevent_default();
+        (self.update)();
+    }
+}
+
+impl<K> Clone for UseSelectionHandle<K> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            update: self.update.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for UseSelectionHandle<K>
+where
+    K: Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let state = self.inner.borrow();
+        let other = other.inner.borrow();
+        state.selected == other.selected
+            && state.anchor == other.anchor
+            && state.focused == other.focused
+    }
+}
+
+/// This hook tracks the selected keys of a list or table,
+/// with range selection, select all/none/invert and keyboard selection.
+///
+/// Keys removed from `keys` are dropped from the selection, keys not in `keys` cannot be
+/// selected and duplicate keys are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Planets)]
+/// fn planets() -> Html {
+///     let planets = vec!["Mercury", "Venus", "Earth", "Mars"];
+///     let selection = use_selection(planets.clone());
+///
+///     let onselect_all = {
+///         let selection = selection.clone();
+///         Callback::from(move |_| selection.toggle_all())
+///     };
+///     let onkeydown = {
+///         let selection = selection.clone();
+///         Callback::from(move |e: KeyboardEvent| selection.handle_keydown(&e))
+///     };
+///
+///     html! {
+///         <table tabindex="0" {onkeydown}>
+///             <tr>
+///                 <th>
+///                     <input type="checkbox" checked={selection.is_all_selected()} onclick={onselect_all} />
+///                 </th>
+///             </tr>
+///             {
+///                 for planets.into_iter().map(|planet| {
+///                     let onclick = {
+///                         let selection = selection.clone();
+///                         // Shift-click selects a range, ctrl-click toggles.
+///                         Callback::from(move |e: MouseEvent| selection.click(planet, &e))
+///                     };
+///                     html! {
+///                         <tr {onclick}>
+///                             <td>
+///                                 <input type="checkbox" checked={selection.is_selected(&planet)} />
+///                             </td>
+///                             <td>{ planet }</td>
+///                         </tr>
+///                     }
+///                 })
+///             }
+///         </table>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_selection<K>(keys: Vec<K>) -> UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    use_selection_with_options(keys, UseSelectionOptions::default())
+}
+
+/// This hook tracks the selected keys of a list or table with options. See [`use_selection`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Themes)]
+/// fn themes() -> Html {
+///     let themes = vec!["Light", "Dark", "System"];
+///     let selection = use_selection_with_options(themes.clone(), UseSelectionOptions::single());
+///
+///     html! {
+///         <ul>
+///             {
+///                 for themes.into_iter().map(|theme| {
+///                     let onclick = {
+///                         let selection = selection.clone();
+///                         Callback::from(move |_| selection.select(theme))
+///                     };
+///                     html! {
+///                         <li {onclick}>
+///                             { theme }
+///                             { if selection.is_selected(&theme) { " ✓" } else { "" } }
+///                         </li>
+///                     }
+///                 })
+///             }
+///         </ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_selection_with_options<K>(
+    keys: Vec<K>,
+    options: UseSelectionOptions,
+) -> UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    let inner = use_mut_ref(|| SelectionState {
+        keys: vec![],
+        indexes: HashMap::new(),
+        selected: HashSet::new(),
+        anchor: None,
+        focused: None,
+        mode: options.mode,
+    });
+    let update = use_update();
+
+    // Prune while rendering, so the selection never refers to removed keys.
+    if inner.borrow().keys != keys {
+        inner.borrow_mut().set_keys(keys);
+    }
+
+    UseSelectionHandle { inner, update }
+}
@@ -1,59 +1,13 @@
-use std::ops::Deref;
-use std::rc::Rc;
-
-use gloo::storage::{SessionStorage, Storage};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use yew::prelude::*;
 
-/// State handle for the [`use_session_storage`] hook.
-pub struct UseSessionStorageHandle<T> {
-    inner: UseStateHandle<Option<T>>,
-    key: Rc<String>,
-}
-
-impl<T> UseSessionStorageHandle<T> {
-    /// Set a `value` for the specified key.
-    pub fn set(&self, value: T)
-    where
-        T: Serialize + Clone,
-    {
-        if SessionStorage::set(&*self.key, value.clone()).is_ok() {
-            self.inner.set(Some(value));
-        }
-    }
-
-    /// Delete a key and it's stored value.
-    pub fn delete(&self) {
-        SessionStorage::delete(&*self.key);
-        self.inner.set(None);
-    }
-}
-
-impl<T> Deref for UseSessionStorageHandle<T> {
-    type Target = Option<T>;
+use super::{use_storage_with_options, SessionStorageBackend, UseStorageHandle, UseStorageOptions};
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
-    }
-}
-
-impl<T> Clone for UseSessionStorageHandle<T> {
-    fn clone(&self) -> Self {
-        Self {
-            inner: self.inner.clone(),
-            key: self.key.clone(),
-        }
-    }
-}
+/// Options for [`use_session_storage_with_options`].
+pub type UseSessionStorageOptions = UseStorageOptions;
 
-impl<T> PartialEq for UseSessionStorageHandle<T>
-where
-    T: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        *self.inner == *other.inner
-    }
-}
+/// State handle for the [`use_session_storage`] hook.
+pub type UseSessionStorageHandle<T> = UseStorageHandle<SessionStorageBackend, T>;
 
 /// A side-effect hook that manages a single sessionStorage key.
 ///
@@ -100,9 +54,65 @@ pub fn use_session_storage<T>(key: String) -> UseSessionStorageHandle<T>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
-    let inner: UseStateHandle<Option<T>> =
-        use_state(|| SessionStorage::get(&key).unwrap_or_default());
-    let key = use_memo((), |_| key);
+    use_session_storage_with_options(key, UseSessionStorageOptions::default())
+}
 
-    UseSessionStorageHandle { inner, key }
+/// A side-effect hook that manages a single sessionStorage key with options.
+/// See [`use_session_storage`] too.
+///
+/// With [`ttl`](UseSessionStorageOptions::ttl) set, values are stored with an expiry time,
+/// expired values are deleted when read and the component re-renders when the current value expires.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(SessionStorage)]
+/// fn session_storage() -> Html {
+///     let storage = use_session_storage_with_options::<String>(
+///         "foo".to_string(),
+///         UseSessionStorageOptions::enable_memory_fallback(),
+///     );
+///
+///     let onclick = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.set("bar".to_string()))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Set to bar" }</button>
+///             <p>
+///                 <b>{ "Current value: " }</b>
+///                 {
+///                     if let Some(value) = &*storage {
+///                         html! { value }
+///                     } else {
+///                         html! {}
+///                     }
+///                 }
+///             </p>
+///             {
+///                 if let Some(error) = &*storage.error {
+///                     html! { <p>{ error.to_string() }</p> }
+///                 } else {
+///                     html! {}
+///                 }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_session_storage_with_options<T>(
+    key: String,
+    options: UseSessionStorageOptions,
+) -> UseSessionStorageHandle<T>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    use_storage_with_options(SessionStorageBackend, key, options)
 }
@@ -1,39 +1,147 @@
 use std::cell::{Ref, RefCell};
-use std::collections::HashSet;
-use std::hash::Hash;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 
-use super::use_update;
+use super::{use_persisted, use_update, Persisted, StorageBackend, StorageWriteError};
+
+/// A set type usable with [`use_set_with_backend`], e.g. [`HashSet`] or [`BTreeSet`] to iterate in order.
+pub trait SetBackend<T> {
+    /// Returns `true` if the set contains the value.
+    fn contains(&self, value: &T) -> bool;
+
+    /// Adds a value, returning whether it was newly inserted.
+    fn insert(&mut self, value: T) -> bool;
+
+    /// Adds a value, replacing the existing equal value, which is returned.
+    fn replace(&mut self, value: T) -> Option<T>;
+
+    /// Removes a value, returning whether it was present.
+    fn remove(&mut self, value: &T) -> bool;
+
+    /// Removes and returns the value equal to the given one, if any.
+    fn take(&mut self, value: &T) -> Option<T>;
+
+    /// Retains only the elements specified by the predicate.
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Removes all values.
+    fn clear(&mut self);
+}
+
+impl<T, S> SetBackend<T> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        HashSet::insert(self, value)
+    }
+
+    fn replace(&mut self, value: T) -> Option<T> {
+        HashSet::replace(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        HashSet::remove(self, value)
+    }
+
+    fn take(&mut self, value: &T) -> Option<T> {
+        HashSet::take(self, value)
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        HashSet::retain(self, f);
+    }
+
+    fn clear(&mut self) {
+        HashSet::clear(self);
+    }
+}
+
+impl<T> SetBackend<T> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        BTreeSet::insert(self, value)
+    }
+
+    fn replace(&mut self, value: T) -> Option<T> {
+        BTreeSet::replace(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        BTreeSet::remove(self, value)
+    }
+
+    fn take(&mut self, value: &T) -> Option<T> {
+        BTreeSet::take(self, value)
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        BTreeSet::retain(self, f);
+    }
+
+    fn clear(&mut self) {
+        BTreeSet::clear(self);
+    }
+}
 
 /// State handle for the [`use_set`] hook.
-pub struct UseSetHandle<T> {
-    inner: Rc<RefCell<HashSet<T>>>,
+pub struct UseSetHandle<T, M = HashSet<T>> {
+    inner: Rc<RefCell<M>>,
     update: Rc<dyn Fn()>,
+    _marker: PhantomData<T>,
 }
 
-impl<T> UseSetHandle<T> {
+impl<T, M> UseSetHandle<T, M>
+where
+    M: SetBackend<T>,
+{
     /// Get immutable ref to the set.
     ///
     /// # Panics
     ///
     /// Panics if the value is currently mutably borrowed
-    pub fn current(&'_ self) -> Ref<'_, HashSet<T>> {
+    pub fn current(&'_ self) -> Ref<'_, M> {
         self.inner.borrow()
     }
 
-    /// Set the hash set.
-    pub fn set(&self, set: HashSet<T>) {
+    /// Set the set.
+    pub fn set(&self, set: M) {
         *self.inner.borrow_mut() = set;
         (self.update)();
     }
 
+    /// Returns `true` if the set contains the value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.inner.borrow().contains(value)
+    }
+
     /// Adds a value to the set.
-    pub fn insert(&self, value: T) -> bool
-    where
-        T: Eq + Hash,
-    {
+    pub fn insert(&self, value: T) -> bool {
         let present = self.inner.borrow_mut().insert(value);
         (self.update)();
         present
@@ -41,29 +149,97 @@ impl<T> UseSetHandle<T> {
 
     /// Adds a value to the set, replacing the existing value,
     /// if any, that is equal to the given one. Returns the replaced value.
-    pub fn replace(&self, value: T) -> Option<T>
-    where
-        T: Eq + Hash,
-    {
+    pub fn replace(&self, value: T) -> Option<T> {
         let v = self.inner.borrow_mut().replace(value);
         (self.update)();
         v
     }
 
     /// Removes a value from the set. Returns whether the value was present in the set.
-    pub fn remove(&self, value: &T) -> bool
-    where
-        T: Eq + Hash,
-    {
+    pub fn remove(&self, value: &T) -> bool {
         let present = self.inner.borrow_mut().remove(value);
         (self.update)();
         present
     }
 
+    /// Removes the value if present in the set, adds it otherwise,
+    /// e.g. for a checkbox. Returns whether the value is now in the set.
+    pub fn toggle(&self, value: T) -> bool {
+        let present = {
+            let mut set = self.inner.borrow_mut();
+            if set.contains(&value) {
+                set.remove(&value);
+                false
+            } else {
+                set.insert(value);
+                true
+            }
+        };
+        (self.update)();
+        present
+    }
+
+    /// Adds all the values to the set.
+    pub fn extend<I>(&self, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        {
+            let mut set = self.inner.borrow_mut();
+            for value in values {
+                set.insert(value);
+            }
+        }
+        (self.update)();
+    }
+
+    /// Adds all the values of `other` to the set.
+    pub fn union_with<'a, I>(&self, other: I)
+    where
+        T: Clone + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend(other.into_iter().cloned());
+    }
+
+    /// Retains only the values also in `other`.
+    pub fn intersect_with<'a, I>(&self, other: I)
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        {
+            let mut set = self.inner.borrow_mut();
+            let kept: Vec<T> = other
+                .into_iter()
+                .filter_map(|value| set.take(value))
+                .collect();
+            set.clear();
+            for value in kept {
+                set.insert(value);
+            }
+        }
+        (self.update)();
+    }
+
+    /// Removes the values in `other`.
+    pub fn difference_with<'a, I>(&self, other: I)
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        {
+            let mut set = self.inner.borrow_mut();
+            for value in other {
+                set.remove(value);
+            }
+        }
+        (self.update)();
+    }
+
     /// Retains only the elements specified by the predicate.
     pub fn retain<F>(&self, f: F)
     where
-        T: Eq + Hash,
         F: FnMut(&T) -> bool,
     {
         self.inner.borrow_mut().retain(f);
@@ -77,25 +253,60 @@ impl<T> UseSetHandle<T> {
     }
 }
 
-impl<T> Clone for UseSetHandle<T> {
+impl<T, M> Clone for UseSetHandle<T, M> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
             update: self.update.clone(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<T> PartialEq for UseSetHandle<T>
+impl<T, M> PartialEq for UseSetHandle<T, M>
 where
-    T: Eq + Hash,
+    M: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         *self.inner == *other.inner
     }
 }
 
+/// State handle for the [`use_persisted_set`] and [`use_persisted_set_with_backend`] hooks.
+pub struct UsePersistedSetHandle<T, M = HashSet<T>> {
+    set: UseSetHandle<T, M>,
+    /// The error of the last failed write to storage, cleared by the next successful write.
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+}
+
+impl<T, M> Deref for UsePersistedSetHandle<T, M> {
+    type Target = UseSetHandle<T, M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.set
+    }
+}
+
+impl<T, M> Clone for UsePersistedSetHandle<T, M> {
+    fn clone(&self) -> Self {
+        Self {
+            set: self.set.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<T, M> PartialEq for UsePersistedSetHandle<T, M>
+where
+    M: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.set == other.set
+    }
+}
+
 /// A hook that tracks a hash set and provides methods to modify it.
+/// See [`use_set_with_backend`] for sorted sets.
 ///
 /// # Example
 ///
@@ -170,5 +381,176 @@ where
     let inner = use_mut_ref(|| initial_value);
     let update = use_update();
 
-    UseSetHandle { inner, update }
+    UseSetHandle {
+        inner,
+        update,
+        _marker: PhantomData,
+    }
+}
+
+/// A hook that tracks a set of any [`SetBackend`] and provides methods to modify it,
+/// e.g. a [`BTreeSet`] to render values sorted, or your own [`SetBackend`].
+///
+/// # Example
+///
+/// A checkbox group rendered in a stable order:
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Toppings)]
+/// fn toppings() -> Html {
+///     let checked = use_set_with_backend(BTreeSet::from(["Cheese"]));
+///
+///     html! {
+///         <div>
+///             {
+///                 for ["Cheese", "Ham", "Olives"].into_iter().map(|topping| {
+///                     let onchange = {
+///                         let checked = checked.clone();
+///                         Callback::from(move |_| {
+///                             let _ = checked.toggle(topping);
+///                         })
+///                     };
+///                     html! {
+///                         <label>
+///                             <input type="checkbox" checked={checked.contains(&topping)} {onchange} />
+///                             { topping }
+///                         </label>
+///                     }
+///                 })
+///             }
+///             <p>{ checked.current().iter().cloned().collect::<Vec<_>>().join(", ") }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_set_with_backend<T, M>(initial_value: M) -> UseSetHandle<T, M>
+where
+    T: 'static,
+    M: SetBackend<T> + 'static,
+{
+    let inner = use_mut_ref(|| initial_value);
+    let update = use_update();
+
+    UseSetHandle {
+        inner,
+        update,
+        _marker: PhantomData,
+    }
+}
+
+/// A hook that tracks a set persisted in a storage backend, e.g. [`LocalStorageBackend`](super::LocalStorageBackend)
+/// or [`SessionStorageBackend`](super::SessionStorageBackend). See [`use_set`] too.
+///
+/// The set is rehydrated from the `key` on mount, falling back to `initial_value`,
+/// written through on every mutation and kept in sync with other hooks and tabs using the same key,
+/// going back to `initial_value` when the key is removed.
+/// When a write fails the collection is kept in memory only and the failure is recorded in `error`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Favorites)]
+/// fn favorites() -> Html {
+///     let favorites = use_persisted_set::<LocalStorageBackend, String>(
+///         "favorites".to_string(),
+///         HashSet::new(),
+///     );
+///
+///     let onclick = {
+///         let favorites = favorites.clone();
+///         Callback::from(move |_| {
+///             let _ = favorites.insert("Earth".to_string());
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Favorite Earth" }</button>
+///             {
+///                 for favorites.current().iter().map(|v| {
+///                     html! {
+///                         <p><b>{ v }</b></p>
+///                     }
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_set<S, T>(key: String, initial_value: HashSet<T>) -> UsePersistedSetHandle<T>
+where
+    S: StorageBackend + Default,
+    T: Eq + Hash + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    use_persisted_set_with_backend::<S, T, _>(key, initial_value)
+}
+
+/// A hook that tracks a set of any [`SetBackend`] persisted in a storage backend.
+/// See [`use_persisted_set`] and [`use_set_with_backend`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Tags)]
+/// fn tags() -> Html {
+///     // Rendered sorted.
+///     let tags = use_persisted_set_with_backend::<LocalStorageBackend, String, _>(
+///         "tags".to_string(),
+///         BTreeSet::new(),
+///     );
+///
+///     html! {
+///         <div>
+///             {
+///                 for tags.current().iter().map(|v| {
+///                     html! {
+///                         <p><b>{ v }</b></p>
+///                     }
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_set_with_backend<S, T, M>(
+    key: String,
+    initial_value: M,
+) -> UsePersistedSetHandle<T, M>
+where
+    S: StorageBackend + Default,
+    T: 'static,
+    M: SetBackend<T> + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    let Persisted {
+        inner,
+        update,
+        error,
+    } = use_persisted::<S, _, _>(key, || initial_value);
+
+    UsePersistedSetHandle {
+        set: UseSetHandle {
+            inner,
+            update,
+            _marker: PhantomData,
+        },
+        error,
+    }
 }
@@ -0,0 +1,378 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+/// Number of transitions kept in the log by default.
+const DEFAULT_LOG_CAPACITY: usize = 100;
+
+type Transition<S, E> = Box<dyn Fn(&S, &E) -> Option<S>>;
+type Guard<S, E> = Box<dyn Fn(&S, &E, &S) -> bool>;
+type Action<E> = Box<dyn Fn(&E)>;
+
+/// Declaration of the transitions of a [`use_state_machine`] hook,
+/// along with its guards and entry/exit actions.
+pub struct StateMachine<S, E> {
+    transition: Transition<S, E>,
+    guards: Vec<Guard<S, E>>,
+    on_entry: Vec<(S, Action<E>)>,
+    on_exit: Vec<(S, Action<E>)>,
+    log_capacity: usize,
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: PartialEq + 'static,
+    E: 'static,
+{
+    /// Declare transitions with a function returning the next state of a state and an event,
+    /// or `None` if the event is not handled in the state.
+    pub fn new<F>(transition: F) -> Self
+    where
+        F: Fn(&S, &E) -> Option<S> + 'static,
+    {
+        Self {
+            transition: Box::new(transition),
+            guards: vec![],
+            on_entry: vec![],
+            on_exit: vec![],
+            log_capacity: DEFAULT_LOG_CAPACITY,
+        }
+    }
+
+    /// Declare transitions with a table of `(from, event, to)` rows.
+    pub fn from_table(table: Vec<(S, E, S)>) -> Self
+    where
+        S: Clone,
+        E: PartialEq,
+    {
+        Self::new(move |state, event| {
+            table
+                .iter()
+                .find(|(from, e, _)| from == state && e == event)
+                .map(|(_, _, to)| to.clone())
+        })
+    }
+
+    /// Add a guard, called with `(from, event, to)`.
+    /// A transition only happens if all guards return `true`.
+    pub fn guard<F>(mut self, guard: F) -> Self
+    where
+        F: Fn(&S, &E, &S) -> bool + 'static,
+    {
+        self.guards.push(Box::new(guard));
+        self
+    }
+
+    /// Add an action run with the event when `state` is entered from another state.
+    pub fn on_entry<F>(mut self, state: S, action: F) -> Self
+    where
+        F: Fn(&E) + 'static,
+    {
+        self.on_entry.push((state, Box::new(action)));
+        self
+    }
+
+    /// Add an action run with the event when `state` is left for another state.
+    pub fn on_exit<F>(mut self, state: S, action: F) -> Self
+    where
+        F: Fn(&E) + 'static,
+    {
+        self.on_exit.push((state, Box::new(action)));
+        self
+    }
+
+    /// Keep at most `capacity` transitions in the log, `0` disables the log.
+    /// Defaults to `100`.
+    pub fn log_capacity(mut self, capacity: usize) -> Self {
+        self.log_capacity = capacity;
+        self
+    }
+
+    /// The next state of `state` for `event`, if the transition exists and all guards pass.
+    fn next(&self, state: &S, event: &E) -> Option<S> {
+        (self.transition)(state, event)
+            .filter(|next| self.guards.iter().all(|guard| guard(state, event, next)))
+    }
+
+    /// Run the exit actions of `from` and the entry actions of `to`, if the state changed.
+    fn run_actions(&self, from: &S, event: &E, to: &S) {
+        if from == to {
+            return;
+        }
+        for (state, action) in &self.on_exit {
+            if state == from {
+                action(event);
+            }
+        }
+        for (state, action) in &self.on_entry {
+            if state == to {
+                action(event);
+            }
+        }
+    }
+}
+
+/// A transition recorded in the log of a [`use_state_machine`] hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateTransition<S, E> {
+    /// The state before the transition.
+    pub from: S,
+    /// The event causing the transition.
+    pub event: E,
+    /// The state after the transition.
+    pub to: S,
+    /// When the transition happened, in milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+enum StateMachineAction<E> {
+    /// An event and when it was sent.
+    Send(E, f64),
+    Reset,
+}
+
+struct UseStateMachineReducer<S, E> {
+    state: S,
+    initial: Rc<S>,
+    machine: Rc<StateMachine<S, E>>,
+    /// Replaced on every transition, self-transitions included.
+    log: Rc<VecDeque<StateTransition<S, E>>>,
+}
+
+impl<S, E> Reducible for UseStateMachineReducer<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    type Action = StateMachineAction<E>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let (state, log) = match action {
+            StateMachineAction::Send(event, timestamp) => {
+                let next = match self.machine.next(&self.state, &event) {
+                    Some(next) => next,
+                    None => return self,
+                };
+
+                let mut log = (*self.log).clone();
+                let capacity = self.machine.log_capacity;
+                if capacity > 0 {
+                    while log.len() >= capacity {
+                        log.pop_front();
+                    }
+                    log.push_back(StateTransition {
+                        from: self.state.clone(),
+                        event,
+                        to: next.clone(),
+                        timestamp,
+                    });
+                }
+
+                (next, log)
+            }
+            StateMachineAction::Reset => ((*self.initial).clone(), VecDeque::new()),
+        };
+
+        Self {
+            state,
+            initial: self.initial.clone(),
+            machine: self.machine.clone(),
+            log: Rc::new(log),
+        }
+        .into()
+    }
+}
+
+impl<S, E> PartialEq for UseStateMachineReducer<S, E>
+where
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.log, &other.log)
+    }
+}
+
+/// State handle for the [`use_state_machine`] hook.
+pub struct UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    inner: UseReducerHandle<UseStateMachineReducer<S, E>>,
+}
+
+impl<S, E> UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    /// Send an event, transitioning to the next state if the current state handles it
+    /// and all guards pass. Ignored otherwise.
+    /// Exit and entry actions run once the transition is done.
+    pub fn send(&self, event: E) {
+        let from = self.inner.state.clone();
+        let log = self.inner.log.clone();
+        self.inner
+            .dispatch(StateMachineAction::Send(event.clone(), js_sys::Date::now()));
+
+        // The handle reads the latest state, the log is only replaced by a transition.
+        if !Rc::ptr_eq(&log, &self.inner.log) {
+            self.inner
+                .machine
+                .run_actions(&from, &event, &self.inner.state);
+        }
+    }
+
+    /// Returns `true` if sending the event would cause a transition in the current state.
+    pub fn can(&self, event: &E) -> bool {
+        self.inner.machine.next(&self.inner.state, event).is_some()
+    }
+
+    /// Reset to the initial state, clearing the log. Entry and exit actions are not run.
+    pub fn reset(&self) {
+        self.inner.dispatch(StateMachineAction::Reset);
+    }
+
+    /// The recent transitions, oldest first.
+    pub fn log(&self) -> &VecDeque<StateTransition<S, E>> {
+        &self.inner.log
+    }
+}
+
+impl<S, E> Deref for UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner.state
+    }
+}
+
+impl<S, E> Clone for UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S, E> PartialEq for UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+impl<S, E> fmt::Debug for UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + fmt::Debug + 'static,
+    E: Clone + fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseStateMachineHandle")
+            .field("state", &self.inner.state)
+            .field("log", &*self.inner.log)
+            .finish()
+    }
+}
+
+/// This hook is used to manage the state of a UI flow as a state machine,
+/// with states and events as enums.
+///
+/// The `machine` is only read on mount.
+/// Entry and exit actions only run when the state changes, after the transition.
+/// Every transition is logged and re-renders, self-transitions included.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Upload {
+///     Idle,
+///     Uploading,
+///     Done,
+///     Failed,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Event {
+///     Start,
+///     Succeed,
+///     Fail,
+///     Retry,
+/// }
+///
+/// #[function_component(Uploader)]
+/// fn uploader() -> Html {
+///     let upload = use_state_machine(
+///         Upload::Idle,
+///         StateMachine::from_table(vec![
+///             (Upload::Idle, Event::Start, Upload::Uploading),
+///             (Upload::Uploading, Event::Succeed, Upload::Done),
+///             (Upload::Uploading, Event::Fail, Upload::Failed),
+///             (Upload::Failed, Event::Retry, Upload::Uploading),
+///         ])
+///         .on_entry(Upload::Done, |_| log::info!("Upload finished")),
+///     );
+///
+///     let onstart = {
+///         let upload = upload.clone();
+///         Callback::from(move |_| upload.send(Event::Start))
+///     };
+///     let onretry = {
+///         let upload = upload.clone();
+///         Callback::from(move |_| upload.send(Event::Retry))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onstart} disabled={!upload.can(&Event::Start)}>{ "Upload" }</button>
+///             <button onclick={onretry} disabled={!upload.can(&Event::Retry)}>{ "Retry" }</button>
+///             <p>
+///                 <b>{ "Current state: " }</b>
+///                 { format!("{:?}", *upload) }
+///             </p>
+///             {
+///                 for upload.log().iter().map(|t| html! {
+///                     <p>{ format!("{:?} --{:?}--> {:?}", t.from, t.event, t.to) }</p>
+///                 })
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_state_machine<S, E>(
+    initial: S,
+    machine: StateMachine<S, E>,
+) -> UseStateMachineHandle<S, E>
+where
+    S: Clone + PartialEq + 'static,
+    E: Clone + 'static,
+{
+    let inner = use_reducer(move || UseStateMachineReducer {
+        state: initial.clone(),
+        initial: Rc::new(initial),
+        machine: Rc::new(machine),
+        log: Rc::default(),
+    });
+
+    UseStateMachineHandle { inner }
+}
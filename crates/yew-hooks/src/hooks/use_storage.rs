@@ -0,0 +1,906 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use gloo::utils::window;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CustomEvent, CustomEventInit, StorageEvent};
+use yew::prelude::*;
+
+use super::{use_event_with_window, use_latest, use_update};
+
+/// Name of the window event dispatched after a key is written by the storage hooks,
+/// so other hooks in the same document can sync. Its `detail` is the changed key.
+const STORAGE_CHANGE_EVENT: &str = "yew-hooks-storage";
+
+/// Error returned when a value cannot be written to storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageWriteError {
+    /// The value could not be serialized to JSON.
+    Serialize(String),
+    /// The storage quota has been exceeded.
+    QuotaExceeded,
+    /// The storage is not available, e.g. disabled by the user or blocked in private mode.
+    Unavailable(String),
+}
+
+impl StorageWriteError {
+    /// Convert an exception thrown by the Web Storage API.
+    pub fn from_js(error: JsValue) -> Self {
+        let name = js_sys::Reflect::get(&error, &JsValue::from_str("name"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .unwrap_or_default();
+        match name.as_str() {
+            // `NS_ERROR_DOM_QUOTA_REACHED` is used by older versions of Firefox.
+            "QuotaExceededError" | "NS_ERROR_DOM_QUOTA_REACHED" => Self::QuotaExceeded,
+            _ => Self::Unavailable(
+                js_sys::Reflect::get(&error, &JsValue::from_str("message"))
+                    .ok()
+                    .and_then(|v| v.as_string())
+                    .unwrap_or(name),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for StorageWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(message) => write!(f, "failed to serialize value: {}", message),
+            Self::QuotaExceeded => write!(f, "storage quota exceeded"),
+            Self::Unavailable(message) => write!(f, "storage is unavailable: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for StorageWriteError {}
+
+/// A key-value storage backend for [`use_storage`] and [`use_storage_keys`].
+///
+/// Implement it on your own type to persist values elsewhere, e.g. in a native bridge.
+/// The backend is an instance, so it can carry state such as a namespace or a handle.
+/// Hooks which don't take a backend, like [`use_storage`], create it with [`Default`].
+pub trait StorageBackend: 'static {
+    /// Get the raw value stored for the `key`.
+    fn get_item(&self, key: &str) -> Option<String>;
+
+    /// Store the raw `value` for the `key`.
+    fn set_item(&self, key: &str, value: &str) -> Result<(), StorageWriteError>;
+
+    /// Remove the `key` and its stored value.
+    fn remove_item(&self, key: &str);
+
+    /// All the stored keys.
+    fn keys(&self) -> Vec<String>;
+
+    /// Whether changes made in other tabs are reported by the window `storage` event,
+    /// which browsers only fire for `localStorage`.
+    fn syncs_across_tabs(&self) -> bool {
+        false
+    }
+}
+
+/// Get all the keys of a Web Storage area.
+fn web_storage_keys(storage: Option<web_sys::Storage>) -> Vec<String> {
+    storage
+        .map(|storage| {
+            let length = storage.length().unwrap_or_default();
+            (0..length)
+                .filter_map(|index| storage.key(index).ok().flatten())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The `localStorage` backend.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalStorageBackend;
+
+impl LocalStorageBackend {
+    fn raw() -> Option<web_sys::Storage> {
+        window().local_storage().ok().flatten()
+    }
+}
+
+impl StorageBackend for LocalStorageBackend {
+    fn get_item(&self, key: &str) -> Option<String> {
+        Self::raw().and_then(|storage| storage.get_item(key).ok().flatten())
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), StorageWriteError> {
+        Self::raw()
+            .ok_or_else(|| StorageWriteError::Unavailable("localStorage is not accessible".into()))?
+            .set_item(key, value)
+            .map_err(StorageWriteError::from_js)
+    }
+
+    fn remove_item(&self, key: &str) {
+        if let Some(storage) = Self::raw() {
+            let _ = storage.remove_item(key);
+        }
+    }
+
+    fn keys(&self) -> Vec<String> {
+        web_storage_keys(Self::raw())
+    }
+
+    fn syncs_across_tabs(&self) -> bool {
+        true
+    }
+}
+
+/// The `sessionStorage` backend.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SessionStorageBackend;
+
+impl SessionStorageBackend {
+    fn raw() -> Option<web_sys::Storage> {
+        window().session_storage().ok().flatten()
+    }
+}
+
+impl StorageBackend for SessionStorageBackend {
+    fn get_item(&self, key: &str) -> Option<String> {
+        Self::raw().and_then(|storage| storage.get_item(key).ok().flatten())
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), StorageWriteError> {
+        Self::raw()
+            .ok_or_else(|| {
+                StorageWriteError::Unavailable("sessionStorage is not accessible".into())
+            })?
+            .set_item(key, value)
+            .map_err(StorageWriteError::from_js)
+    }
+
+    fn remove_item(&self, key: &str) {
+        if let Some(storage) = Self::raw() {
+            let _ = storage.remove_item(key);
+        }
+    }
+
+    fn keys(&self) -> Vec<String> {
+        web_storage_keys(Self::raw())
+    }
+}
+
+thread_local! {
+    static MEMORY_STORAGE: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// An in-memory backend, shared by all components and cleared when the page is reloaded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MemoryStorageBackend;
+
+impl StorageBackend for MemoryStorageBackend {
+    fn get_item(&self, key: &str) -> Option<String> {
+        MEMORY_STORAGE.with(|storage| storage.borrow().get(key).cloned())
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), StorageWriteError> {
+        MEMORY_STORAGE.with(|storage| {
+            storage
+                .borrow_mut()
+                .insert(key.to_string(), value.to_string())
+        });
+        Ok(())
+    }
+
+    fn remove_item(&self, key: &str) {
+        MEMORY_STORAGE.with(|storage| storage.borrow_mut().remove(key));
+    }
+
+    fn keys(&self) -> Vec<String> {
+        MEMORY_STORAGE.with(|storage| storage.borrow().keys().cloned().collect())
+    }
+}
+
+/// Options for [`use_storage_with_options`].
+#[derive(Default)]
+pub struct UseStorageOptions {
+    /// Namespace prepended to the key, e.g. `"tenant-a:"`.
+    pub prefix: Option<String>,
+    /// Keep the value in memory when it cannot be written to storage,
+    /// so the app keeps working when storage is unavailable.
+    pub memory_fallback: bool,
+    /// Expire values after the duration since they were set.
+    /// Expired values are treated as absent and deleted from storage.
+    pub ttl: Option<Duration>,
+}
+
+impl UseStorageOptions {
+    /// Fall back to an in-memory value when writing to storage fails.
+    pub const fn enable_memory_fallback() -> Self {
+        Self {
+            prefix: None,
+            memory_fallback: true,
+            ttl: None,
+        }
+    }
+
+    /// Expire values after `ttl` since they were set.
+    pub const fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            prefix: None,
+            memory_fallback: false,
+            ttl: Some(ttl),
+        }
+    }
+
+    /// Prepend `prefix` to the key.
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            memory_fallback: false,
+            ttl: None,
+        }
+    }
+}
+
+/// A stored value wrapped with its expiry time, used when a TTL is set.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expiring<T> {
+    value: T,
+    /// Milliseconds since the Unix epoch.
+    expires_at: f64,
+}
+
+/// A value read from storage along with its expiry time.
+pub(crate) struct StoredValue<T> {
+    pub value: Option<T>,
+    pub expires_at: Option<f64>,
+}
+
+impl<T> Default for StoredValue<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            expires_at: None,
+        }
+    }
+}
+
+/// Returns the expiry time of a value set now with the `ttl`.
+fn expires_at(ttl: Option<Duration>) -> Option<f64> {
+    ttl.map(|ttl| js_sys::Date::now() + ttl.as_millis() as f64)
+}
+
+/// Serialize and write a `value` to storage,
+/// wrapped with its expiry time if `expires_at` is set.
+pub(crate) fn write_storage<S, T>(
+    backend: &S,
+    key: &str,
+    value: &T,
+    expires_at: Option<f64>,
+) -> Result<(), StorageWriteError>
+where
+    S: StorageBackend,
+    T: Serialize,
+{
+    let value = match expires_at {
+        Some(expires_at) => serde_json::to_string(&Expiring { value, expires_at }),
+        None => serde_json::to_string(value),
+    }
+    .map_err(|e| StorageWriteError::Serialize(e.to_string()))?;
+    backend.set_item(key, &value)
+}
+
+/// Read and deserialize a value and its expiry time from storage.
+/// Expired values are deleted and read as absent.
+pub(crate) fn read_storage<S, T>(backend: &S, key: &str) -> StoredValue<T>
+where
+    S: StorageBackend,
+    T: for<'de> Deserialize<'de>,
+{
+    let raw = match backend.get_item(key) {
+        Some(raw) => raw,
+        None => return StoredValue::default(),
+    };
+    match serde_json::from_str::<Expiring<T>>(&raw) {
+        Ok(expiring) if expiring.expires_at <= js_sys::Date::now() => {
+            backend.remove_item(key);
+            StoredValue::default()
+        }
+        Ok(expiring) => StoredValue {
+            value: Some(expiring.value),
+            expires_at: Some(expiring.expires_at),
+        },
+        Err(_) => StoredValue {
+            value: serde_json::from_str(&raw).ok(),
+            expires_at: None,
+        },
+    }
+}
+
+/// Let other storage hooks in the document know that the `key` has changed.
+fn notify_change(key: &str) {
+    let init = CustomEventInit::new();
+    init.set_detail(&JsValue::from_str(key));
+    if let Ok(event) = CustomEvent::new_with_event_init_dict(STORAGE_CHANGE_EVENT, &init) {
+        let _ = window().dispatch_event(&event);
+    }
+}
+
+/// Run the `callback` on window `storage` events, i.e. changes made in other tabs.
+/// No listener is added for backends which don't sync across tabs.
+#[hook]
+fn use_storage_event<S, F>(backend: Rc<S>, callback: F)
+where
+    S: StorageBackend,
+    F: Fn(StorageEvent) + 'static,
+{
+    let callback = use_latest(callback);
+
+    use_effect_with((), move |_| {
+        let listener = backend.syncs_across_tabs().then(|| {
+            EventListener::new(&window(), "storage", move |e| {
+                if let Some(e) = e.dyn_ref::<StorageEvent>() {
+                    (*callback.current())(e.clone());
+                }
+            })
+        });
+
+        move || drop(listener)
+    });
+}
+
+/// Run the `callback` when `expires_at` is reached.
+#[hook]
+fn use_expiry<F>(expires_at: Option<f64>, callback: F)
+where
+    F: Fn() + 'static,
+{
+    let callback = use_latest(callback);
+    // Bumped to arm the timer again when it fires early.
+    let rearm = use_state(|| 0u32);
+
+    use_effect_with((expires_at, *rearm), move |(expires_at, _)| {
+        let timeout = expires_at.and_then(|expires_at| {
+            let remaining = (expires_at - js_sys::Date::now()).max(0.0);
+            // Browsers fire timers longer than `i32::MAX` immediately,
+            // such values are only checked when they are read.
+            (remaining <= f64::from(i32::MAX)).then(|| {
+                Timeout::new(remaining as u32, move || {
+                    if js_sys::Date::now() < expires_at {
+                        rearm.set(rearm.wrapping_add(1));
+                    } else {
+                        (*callback.current())();
+                    }
+                })
+            })
+        });
+
+        move || drop(timeout)
+    });
+}
+
+/// State handle for the [`use_storage`] hook.
+pub struct UseStorageHandle<S, T> {
+    inner: UseStateHandle<StoredValue<T>>,
+    /// The error of the last failed write, cleared by the next successful write.
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+    key: Rc<String>,
+    memory_fallback: bool,
+    ttl: Option<Duration>,
+    backend: Rc<S>,
+}
+
+impl<S, T> UseStorageHandle<S, T>
+where
+    S: StorageBackend,
+{
+    /// Set a `value` for the specified key.
+    /// Failures are recorded in [`error`](Self::error), see [`try_set`](Self::try_set).
+    pub fn set(&self, value: T)
+    where
+        T: Serialize,
+    {
+        let _ = self.try_set(value);
+    }
+
+    /// Set a `value` for the specified key, returning the error if it cannot be written.
+    ///
+    /// The state is only updated when the write succeeds, unless memory fallback is enabled.
+    pub fn try_set(&self, value: T) -> Result<(), StorageWriteError>
+    where
+        T: Serialize,
+    {
+        let expires_at = expires_at(self.ttl);
+        match write_storage(&*self.backend, &self.key, &value, expires_at) {
+            Ok(()) => {
+                self.inner.set(StoredValue {
+                    value: Some(value),
+                    expires_at,
+                });
+                if self.error.is_some() {
+                    self.error.set(None);
+                }
+                notify_change(&self.key);
+                Ok(())
+            }
+            Err(error) => {
+                if self.memory_fallback {
+                    self.inner.set(StoredValue {
+                        value: Some(value),
+                        expires_at,
+                    });
+                }
+                self.error.set(Some(error.clone()));
+                Err(error)
+            }
+        }
+    }
+
+    /// Delete a key and it's stored value.
+    pub fn delete(&self) {
+        self.backend.remove_item(&self.key);
+        self.inner.set(StoredValue::default());
+        notify_change(&self.key);
+    }
+
+    /// When the current value expires, in milliseconds since the Unix epoch.
+    pub fn expires_at(&self) -> Option<f64> {
+        self.inner.expires_at
+    }
+
+    /// The key in storage, including the prefix.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl<S, T> Deref for UseStorageHandle<S, T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner.value
+    }
+}
+
+impl<S, T> Clone for UseStorageHandle<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            error: self.error.clone(),
+            key: self.key.clone(),
+            memory_fallback: self.memory_fallback,
+            ttl: self.ttl,
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<S, T> PartialEq for UseStorageHandle<S, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.value == other.inner.value && *self.error == *other.error
+    }
+}
+
+/// A side-effect hook that manages a single key of a storage backend,
+/// e.g. [`LocalStorageBackend`], [`SessionStorageBackend`], [`MemoryStorageBackend`]
+/// or your own [`StorageBackend`].
+///
+/// Hooks managing the same key are kept in sync, within the document and across tabs for `localStorage`.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Storage)]
+/// fn storage() -> Html {
+///     let storage = use_storage::<MemoryStorageBackend, String>("foo".to_string());
+///
+///     let onclick = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.set("bar".to_string()))
+///     };
+///     let ondelete = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.delete())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Set to bar" }</button>
+///             <button onclick={ondelete}>{ "Delete" }</button>
+///             <p>
+///                 <b>{ "Current value: " }</b>
+///                 {
+///                     if let Some(value) = &*storage {
+///                         html! { value }
+///                     } else {
+///                         html! {}
+///                     }
+///                 }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage<S, T>(key: String) -> UseStorageHandle<S, T>
+where
+    S: StorageBackend + Default,
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    use_storage_with_options(S::default(), key, UseStorageOptions::default())
+}
+
+/// A side-effect hook that manages a single key of the `backend` with options.
+/// See [`use_storage`] too.
+///
+/// The `backend` and the options are only read on the first render.
+///
+/// With [`prefix`](UseStorageOptions::prefix) set, the key is namespaced so data of
+/// e.g. different tenants is isolated, and can be listed and cleared with [`use_storage_keys`].
+///
+/// With [`ttl`](UseStorageOptions::ttl) set, values are stored with an expiry time,
+/// expired values are deleted when read and the component re-renders when the current value expires.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Storage)]
+/// fn storage() -> Html {
+///     let storage = use_storage_with_options::<_, String>(
+///         LocalStorageBackend,
+///         "theme".to_string(),
+///         UseStorageOptions::with_prefix("tenant-a:"),
+///     );
+///
+///     let onclick = {
+///         let storage = storage.clone();
+///         Callback::from(move |_| storage.set("dark".to_string()))
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Set to dark" }</button>
+///             <p>
+///                 <b>{ storage.key() }</b>
+///                 { ": " }
+///                 {
+///                     if let Some(value) = &*storage {
+///                         html! { value }
+///                     } else {
+///                         html! {}
+///                     }
+///                 }
+///             </p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_with_options<S, T>(
+    backend: S,
+    key: String,
+    options: UseStorageOptions,
+) -> UseStorageHandle<S, T>
+where
+    S: StorageBackend,
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    let key = use_memo((), |_| match &options.prefix {
+        Some(prefix) => format!("{}{}", prefix, key),
+        None => key,
+    });
+    let backend = use_memo((), |_| backend);
+    let inner: UseStateHandle<StoredValue<T>> = use_state(|| read_storage(&*backend, &key));
+    let error = use_state(|| None);
+
+    {
+        let key = key.clone();
+        let inner = inner.clone();
+        let storage = backend.clone();
+        use_storage_event(backend.clone(), move |e: StorageEvent| {
+            if let Some(k) = e.key() {
+                if k == *key {
+                    inner.set(read_storage(&*storage, &key));
+                }
+            }
+        });
+    }
+
+    {
+        let key = key.clone();
+        let inner = inner.clone();
+        let backend = backend.clone();
+        use_event_with_window(STORAGE_CHANGE_EVENT, move |e: CustomEvent| {
+            if e.detail().as_string().as_deref() == Some(key.as_str()) {
+                inner.set(read_storage(&*backend, &key));
+            }
+        });
+    }
+
+    {
+        let key = key.clone();
+        let inner = inner.clone();
+        let backend = backend.clone();
+        let memory_fallback = options.memory_fallback;
+        use_expiry(inner.expires_at, move || {
+            let stored = read_storage(&*backend, &key);
+            // Storage may hold an older value the expired one failed to overwrite.
+            inner.set(if memory_fallback {
+                StoredValue::default()
+            } else {
+                stored
+            });
+        });
+    }
+
+    UseStorageHandle {
+        inner,
+        error,
+        key,
+        memory_fallback: options.memory_fallback,
+        ttl: options.ttl,
+        backend,
+    }
+}
+
+/// State handle for the [`use_storage_keys`] hook.
+pub struct UseStorageKeysHandle<S> {
+    inner: UseStateHandle<Vec<String>>,
+    prefix: Rc<String>,
+    backend: Rc<S>,
+}
+
+impl<S> UseStorageKeysHandle<S>
+where
+    S: StorageBackend,
+{
+    /// Delete all the keys with the prefix and their stored values.
+    pub fn clear(&self) {
+        for key in self.inner.iter() {
+            let key = format!("{}{}", self.prefix, key);
+            self.backend.remove_item(&key);
+            notify_change(&key);
+        }
+        self.inner.set(vec![]);
+    }
+}
+
+impl<S> Deref for UseStorageKeysHandle<S> {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<S> Clone for UseStorageKeysHandle<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            prefix: self.prefix.clone(),
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<S> PartialEq for UseStorageKeysHandle<S> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner && self.prefix == other.prefix
+    }
+}
+
+/// Get the keys with the `prefix`, with the prefix stripped.
+fn keys_with_prefix<S: StorageBackend>(backend: &S, prefix: &str) -> Vec<String> {
+    let mut keys: Vec<String> = backend
+        .keys()
+        .into_iter()
+        .filter_map(|key| key.strip_prefix(prefix).map(ToString::to_string))
+        .collect();
+    keys.sort();
+    keys
+}
+
+/// A side-effect hook that lists and watches the keys of the `backend` starting with `prefix`.
+/// The keys are returned without the prefix and sorted,
+/// so they can be passed to [`use_storage_with_options`] with the same prefix.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(StorageKeys)]
+/// fn storage_keys() -> Html {
+///     let keys = use_storage_keys(LocalStorageBackend, "tenant-a:".to_string());
+///
+///     let onclick = {
+///         let keys = keys.clone();
+///         Callback::from(move |_| keys.clear())
+///     };
+///
+///     html! {
+///         <div>
+///             <button onclick={onclick}>{ "Clear tenant data" }</button>
+///             <ul>
+///                 { for keys.iter().map(|key| html! { <li>{ key }</li> }) }
+///             </ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_storage_keys<S>(backend: S, prefix: String) -> UseStorageKeysHandle<S>
+where
+    S: StorageBackend,
+{
+    let backend = use_memo((), |_| backend);
+    let inner = use_state(|| keys_with_prefix(&*backend, &prefix));
+    let prefix = use_memo(prefix, |prefix| prefix.clone());
+
+    let refresh = {
+        let inner = inner.clone();
+        let prefix = prefix.clone();
+        let backend = backend.clone();
+        move || {
+            let keys = keys_with_prefix(&*backend, &prefix);
+            if *inner != keys {
+                inner.set(keys);
+            }
+        }
+    };
+
+    {
+        let refresh = refresh.clone();
+        use_effect_with(prefix.clone(), move |_| {
+            refresh();
+            || ()
+        });
+    }
+
+    {
+        let refresh = refresh.clone();
+        use_storage_event(backend.clone(), move |_: StorageEvent| refresh());
+    }
+
+    use_event_with_window(STORAGE_CHANGE_EVENT, move |_: CustomEvent| refresh());
+
+    UseStorageKeysHandle {
+        inner,
+        prefix,
+        backend,
+    }
+}
+
+/// A collection backed by [`use_persisted`].
+pub(crate) struct Persisted<C> {
+    pub inner: Rc<RefCell<C>>,
+    pub update: Rc<dyn Fn()>,
+    pub error: UseStateHandle<Option<StorageWriteError>>,
+}
+
+/// Back a collection hook with a key of a storage backend.
+///
+/// Returns the collection, rehydrated from storage or created by `initial_value`,
+/// an update function which writes it through to storage before re-rendering,
+/// and the error of the last failed write, cleared by the next successful write.
+/// The collection is replaced when the key is changed by another hook or tab,
+/// and reset to `initial_value` when the key is removed.
+#[hook]
+pub(crate) fn use_persisted<S, C, F>(key: String, initial_value: F) -> Persisted<C>
+where
+    S: StorageBackend + Default,
+    C: Serialize + for<'de> Deserialize<'de> + 'static,
+    F: FnOnce() -> C,
+{
+    let backend = use_memo((), |_| S::default());
+    // The raw value last written or read, to skip our own change notifications.
+    let raw = use_mut_ref(|| backend.get_item(&key));
+    // The serialized initial collection, restored when the key is removed.
+    let initial = use_mut_ref(|| None);
+    let inner = use_mut_ref(|| {
+        let initial_value = initial_value();
+        *initial.borrow_mut() = serde_json::to_string(&initial_value).ok();
+        raw.borrow()
+            .as_deref()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or(initial_value)
+    });
+    let key = use_memo((), |_| key);
+    let update = use_update();
+    let error = use_state(|| None);
+    // Whether the last write failed, as `error` is only read on the next render.
+    let failed = use_mut_ref(|| false);
+
+    let reload = {
+        let key = key.clone();
+        let raw = raw.clone();
+        let inner = inner.clone();
+        let update = update.clone();
+        let backend = backend.clone();
+        move || {
+            let value = backend.get_item(&key);
+            if *raw.borrow() == value {
+                return;
+            }
+            let collection = match &value {
+                Some(value) => serde_json::from_str(value).ok(),
+                None => initial
+                    .borrow()
+                    .as_deref()
+                    .and_then(|initial| serde_json::from_str(initial).ok()),
+            };
+            if let Some(collection) = collection {
+                *inner.borrow_mut() = collection;
+                update();
+            }
+            *raw.borrow_mut() = value;
+        }
+    };
+
+    {
+        let key = key.clone();
+        let reload = reload.clone();
+        use_storage_event(backend.clone(), move |e: StorageEvent| {
+            if e.key().as_deref() == Some(key.as_str()) {
+                reload();
+            }
+        });
+    }
+
+    {
+        let key = key.clone();
+        use_event_with_window(STORAGE_CHANGE_EVENT, move |e: CustomEvent| {
+            if e.detail().as_string().as_deref() == Some(key.as_str()) {
+                reload();
+            }
+        });
+    }
+
+    let persist: Rc<dyn Fn()> = {
+        let inner = inner.clone();
+        let error = error.clone();
+        Rc::new(move || {
+            // Write failures keep the collection in memory only.
+            let written = serde_json::to_string(&*inner.borrow())
+                .map_err(|e| StorageWriteError::Serialize(e.to_string()))
+                .and_then(|value| backend.set_item(&key, &value).map(|()| value));
+            match written {
+                Ok(value) => {
+                    *raw.borrow_mut() = Some(value);
+                    notify_change(&key);
+                    if failed.replace(false) {
+                        error.set(None);
+                    } else {
+                        update();
+                    }
+                }
+                Err(e) => {
+                    failed.replace(true);
+                    error.set(Some(e));
+                }
+            }
+        })
+    };
+
+    Persisted {
+        inner,
+        update: persist,
+        error,
+    }
+}
@@ -1,32 +1,67 @@
-use std::rc::Rc;
-
 use yew::prelude::*;
 
-use super::{use_mut_latest, use_timeout};
+use super::{use_rate_limit, RateLimit, UseRateLimitHandle};
+
+/// Options for [`use_throttle_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseThrottleOptions {
+    /// Invoke on the leading edge of the wait. Defaults to `true`.
+    pub leading: bool,
+    /// Invoke on the trailing edge of the wait if run during it. Defaults to `false`.
+    pub trailing: bool,
+}
+
+impl UseThrottleOptions {
+    /// Invoke on both edges, so the last run during the wait is not dropped.
+    pub const fn trailing() -> Self {
+        Self {
+            leading: true,
+            trailing: true,
+        }
+    }
+}
+
+impl Default for UseThrottleOptions {
+    fn default() -> Self {
+        Self {
+            leading: true,
+            trailing: false,
+        }
+    }
+}
 
 /// State handle for the [`use_throttle`] hook.
 pub struct UseThrottleHandle {
-    run: Rc<dyn Fn()>,
-    cancel: Rc<dyn Fn()>,
+    inner: UseRateLimitHandle,
 }
 
 impl UseThrottleHandle {
     /// Run the throttle.
     pub fn run(&self) {
-        (self.run)();
+        self.inner.run();
     }
 
     /// Cancel the throttle.
     pub fn cancel(&self) {
-        (self.cancel)();
+        self.inner.cancel();
+    }
+
+    /// Invoke the pending trailing function immediately, if any.
+    pub fn flush(&self) {
+        self.inner.flush();
+    }
+
+    /// Returns `true` if a trailing invocation is waiting for the wait to elapse.
+    /// Changes do not re-render the component by themselves.
+    pub fn is_pending(&self) -> bool {
+        self.inner.is_pending()
     }
 }
 
 impl Clone for UseThrottleHandle {
     fn clone(&self) -> Self {
         Self {
-            run: self.run.clone(),
-            cancel: self.cancel.clone(),
+            inner: self.inner.clone(),
         }
     }
 }
@@ -75,39 +110,68 @@ pub fn use_throttle<Callback>(callback: Callback, millis: u32) -> UseThrottleHan
 where
     Callback: FnMut() + 'static,
 {
-    let throttled = use_mut_ref(|| false);
-    let callback_ref = use_mut_latest(callback);
-    let timeout = {
-        let throttled = throttled.clone();
-        use_timeout(
-            move || {
-                *throttled.borrow_mut() = false;
-            },
-            millis,
-        )
-    };
-
-    let run = {
-        let throttled = throttled.clone();
-        let timeout = timeout.clone();
-        Rc::new(move || {
-            let throttled_value = *throttled.borrow();
-            if !throttled_value {
-                let callback_ref = callback_ref.current();
-                let callback = &mut *callback_ref.borrow_mut();
-                callback();
-                *throttled.borrow_mut() = true;
-                timeout.reset();
-            }
-        })
-    };
+    use_throttle_with_options(callback, millis, UseThrottleOptions::default())
+}
 
-    let cancel = {
-        Rc::new(move || {
-            timeout.cancel();
-            *throttled.borrow_mut() = false;
-        })
-    };
+/// A hook that throttles invoking a function with options,
+/// e.g. also on the trailing edge so the last run is not dropped. See [`use_throttle`] too.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(ScrollSpy)]
+/// fn scroll_spy() -> Html {
+///     let position = use_mut_ref(|| 0.0);
+///     let reported = use_state(|| 0.0);
+///
+///     let throttle = {
+///         let position = position.clone();
+///         let reported = reported.clone();
+///         use_throttle_with_options(
+///             move || reported.set(*position.borrow()),
+///             200,
+///             UseThrottleOptions::trailing(),
+///         )
+///     };
+///
+///     let onscroll = {
+///         Callback::from(move |e: Event| {
+///             let element: web_sys::Element = e.target_unchecked_into();
+///             *position.borrow_mut() = element.scroll_top() as f64;
+///             throttle.run();
+///         })
+///     };
+///
+///     html! {
+///         <div {onscroll} style="height: 100px; overflow: auto;">
+///             <div style="height: 1000px;">{ "Scrolled to " }{ *reported }</div>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_throttle_with_options<Callback>(
+    callback: Callback,
+    millis: u32,
+    options: UseThrottleOptions,
+) -> UseThrottleHandle
+where
+    Callback: FnMut() + 'static,
+{
+    let inner = use_rate_limit(
+        callback,
+        millis,
+        RateLimit {
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: None,
+            debounce: false,
+        },
+    );
 
-    UseThrottleHandle { run, cancel }
+    UseThrottleHandle { inner }
 }
@@ -3,7 +3,7 @@ use std::rc::Rc;
 
 use yew::prelude::*;
 
-use super::use_throttle;
+use super::{use_throttle_with_options, UseThrottleOptions};
 
 /// State handle for the [`use_throttle_state`] hook.
 pub struct UseThrottleStateHandle<T> {
@@ -72,6 +72,46 @@ where
 /// ```
 #[hook]
 pub fn use_throttle_state<T, F>(init_fn: F, millis: u32) -> UseThrottleStateHandle<T>
+where
+    T: 'static,
+    F: FnOnce() -> T,
+{
+    use_throttle_state_with_options(init_fn, millis, UseThrottleOptions::default())
+}
+
+/// A hook that throttles updating state with [`UseThrottleOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(ThrottleState)]
+/// fn throttle_state() -> Html {
+///     // Also keeps the last click made while throttled.
+///     let state = use_throttle_state_with_options(|| 0, 2000, UseThrottleOptions::trailing());
+///
+///     let onclick = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(*state + 1))
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Click fast!" }</button>
+///             <b>{ "State: " }</b> {*state}
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_throttle_state_with_options<T, F>(
+    init_fn: F,
+    millis: u32,
+    options: UseThrottleOptions,
+) -> UseThrottleStateHandle<T>
 where
     T: 'static,
     F: FnOnce() -> T,
@@ -81,7 +121,7 @@ where
     let throttle = {
         let value = value.clone();
         let inner = inner.clone();
-        use_throttle(
+        use_throttle_with_options(
             move || {
                 let value = (*value.borrow_mut()).take();
                 if let Some(value) = value {
@@ -89,6 +129,7 @@ where
                 }
             },
             millis,
+            options,
         )
     };
 
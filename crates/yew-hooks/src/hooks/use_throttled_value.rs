@@ -0,0 +1,83 @@
+use yew::prelude::*;
+
+use super::{use_rate_limited_value, RateLimit, UseThrottleOptions};
+
+/// A hook that returns a copy of `value` which updates at most once every `millis`.
+/// Both the first and the last change in a window are followed,
+/// like [`UseThrottleOptions::trailing`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(ThrottledValue)]
+/// fn throttled_value() -> Html {
+///     let state = use_window_scroll();
+///     // Re-renders children at most 10 times a second while scrolling.
+///     let position = use_throttled_value(state, 100);
+///
+///     html! {
+///         <>
+///             <b>{ " X: " }</b>
+///             { position.0 }
+///             <b>{ " Y: " }</b>
+///             { position.1 }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_throttled_value<T>(value: T, millis: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    use_throttled_value_with_options(value, millis, UseThrottleOptions::trailing())
+}
+
+/// A hook that returns a throttled copy of `value` with [`UseThrottleOptions`].
+/// Without `trailing`, changes made while throttled are dropped until `value` changes again.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(ThrottledValue)]
+/// fn throttled_value() -> Html {
+///     let counter = use_counter(0);
+///     let throttled = use_throttled_value_with_options(*counter, 2000, UseThrottleOptions::default());
+///
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.increase())
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick}>{ "Click fast!" }</button>
+///             <b>{ "Throttled: " }</b> { throttled }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_throttled_value_with_options<T>(value: T, millis: u32, options: UseThrottleOptions) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    use_rate_limited_value(
+        value,
+        millis,
+        RateLimit {
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: None,
+            debounce: false,
+        },
+    )
+}
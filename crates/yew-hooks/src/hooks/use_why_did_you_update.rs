@@ -0,0 +1,152 @@
+use std::fmt::Debug;
+
+use yew::prelude::*;
+
+/// This hook logs in console which fields of props changed when a component re-renders.
+/// Fields are compared by their pretty printed [`Debug`] representation,
+/// so it works for props structs, tuples of state and any other `Debug` value.
+///
+/// Logging is only compiled with the `why-did-you-update` feature,
+/// otherwise this hook does nothing, so it can be left in place for release builds.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// use yew_hooks::prelude::*;
+///
+/// #[function_component(Profile)]
+/// fn profile(props: &Props) -> Html {
+///     // Logs e.g. `Profile updated: name: "a" -> "b"`.
+///     use_why_did_you_update("Profile".to_string(), props.clone());
+///
+///     html! {
+///         <>
+///             <b>{ " name: " }</b> { &props.name }
+///             <b>{ " age: " }</b> { props.age }
+///         </>
+///     }
+/// }
+///
+/// #[derive(Debug, Properties, PartialEq, Clone)]
+/// struct Props {
+///     pub name: String,
+///     pub age: u32,
+/// }
+/// ```
+#[hook]
+pub fn use_why_did_you_update<T>(name: String, props: T)
+where
+    T: Debug + 'static,
+{
+    #[cfg(feature = "why-did-you-update")]
+    {
+        let prev = use_mut_ref(|| None::<DebugRepr>);
+        let current = DebugRepr {
+            compact: format!("{props:?}"),
+            pretty: format!("{props:#?}"),
+        };
+
+        use_effect(move || {
+            let prev = prev.replace(Some(current.clone()));
+            if let Some(prev) = prev {
+                let changes = diff_debug(&prev, &current);
+                if !changes.is_empty() {
+                    log::debug!("{} updated: {}", name, changes.join(", "));
+                }
+            }
+            || ()
+        });
+    }
+
+    #[cfg(not(feature = "why-did-you-update"))]
+    let _ = (name, props);
+}
+
+/// The `Debug` representations of a value.
+#[cfg(feature = "why-did-you-update")]
+#[derive(Clone)]
+struct DebugRepr {
+    compact: String,
+    pretty: String,
+}
+
+/// Split a pretty printed `Debug` representation into its top level fields,
+/// named by field for structs and by index for tuples and sequences.
+#[cfg(feature = "why-did-you-update")]
+fn debug_fields(debug: &str) -> (&str, Vec<(String, String)>) {
+    let mut lines = debug.lines();
+    let header = lines.next().unwrap_or_default();
+    let mut fields: Vec<(String, String)> = vec![];
+
+    for line in lines {
+        match line.strip_prefix("    ") {
+            // Deeper lines and closing brackets of a multi-line value continue the field.
+            Some(rest) if !rest.starts_with([' ', ')', ']', '}']) => {
+                let (name, value) = match rest.split_once(": ") {
+                    Some((name, value))
+                        if name.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+                    {
+                        (name.to_string(), value)
+                    }
+                    _ => (fields.len().to_string(), rest),
+                };
+                fields.push((name, value.to_string()));
+            }
+            Some(rest) => {
+                if let Some((_, value)) = fields.last_mut() {
+                    value.push(' ');
+                    value.push_str(rest.trim());
+                }
+            }
+            // The closing bracket.
+            None => {}
+        }
+    }
+
+    for (_, value) in &mut fields {
+        if value.ends_with(',') {
+            value.pop();
+        }
+        *value = value
+            .replace("( ", "(")
+            .replace("[ ", "[")
+            .replace(", )", ")")
+            .replace(", ]", "]")
+            .replace(", }", " }");
+    }
+
+    (header, fields)
+}
+
+/// Describe the fields that differ between two `Debug` representations,
+/// or the whole values when they have no fields in common.
+#[cfg(feature = "why-did-you-update")]
+fn diff_debug(prev: &DebugRepr, next: &DebugRepr) -> Vec<String> {
+    if prev.pretty == next.pretty {
+        return vec![];
+    }
+
+    let (prev_header, prev_fields) = debug_fields(&prev.pretty);
+    let (next_header, next_fields) = debug_fields(&next.pretty);
+    if prev_header != next_header || next_fields.is_empty() {
+        return vec![format!("{} -> {}", prev.compact, next.compact)];
+    }
+
+    let mut changes = vec![];
+    for (name, value) in &next_fields {
+        match prev_fields.iter().find(|(prev_name, _)| prev_name == name) {
+            Some((_, prev_value)) if prev_value == value => {}
+            Some((_, prev_value)) => changes.push(format!("{name}: {prev_value} -> {value}")),
+            None => changes.push(format!("{name}: <none> -> {value}")),
+        }
+    }
+    for (name, prev_value) in &prev_fields {
+        if !next_fields.iter().any(|(next_name, _)| next_name == name) {
+            changes.push(format!("{name}: {prev_value} -> <none>"));
+        }
+    }
+
+    changes
+}
@@ -9,7 +9,9 @@ use common::obtain_result;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
-use yew_hooks::use_counter;
+use yew_hooks::{
+    use_counter, use_counter_with_options, use_effect_once, BoundaryMode, UseCounterOptions,
+};
 
 #[wasm_bindgen_test]
 async fn use_counter_works() {
@@ -36,3 +38,116 @@ async fn use_counter_works() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "5");
 }
+
+#[wasm_bindgen_test]
+async fn use_counter_with_options_clamps() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let max = use_state(|| 3u32);
+        let counter = use_counter_with_options(0u32, UseCounterOptions::with_range(0, *max));
+        if !counter.is_at_max() {
+            counter.increase_by(2);
+        } else if *max == 3 {
+            // Raising the bound lets the counter go on.
+            max.set(5);
+        } else if *max == 5 {
+            // Lowering the bound clamps the value.
+            max.set(4);
+        }
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">{*counter}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "4");
+}
+
+#[wasm_bindgen_test]
+async fn use_counter_with_options_wraps_at_type_limits() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let digit = use_counter_with_options(
+            0u32,
+            UseCounterOptions {
+                min: Some(0),
+                max: Some(9),
+                mode: BoundaryMode::Wrap,
+                ..Default::default()
+            },
+        );
+        let byte = use_counter_with_options(
+            u8::MAX,
+            UseCounterOptions {
+                min: Some(250),
+                max: Some(u8::MAX),
+                mode: BoundaryMode::Wrap,
+                ..Default::default()
+            },
+        );
+        {
+            let digit = digit.clone();
+            let byte = byte.clone();
+            use_effect_once(move || {
+                digit.decrease();
+                byte.increase();
+                || ()
+            });
+        }
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">{format!("{} {}", *digit, *byte)}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "9 250");
+}
+
+#[wasm_bindgen_test]
+async fn use_counter_with_options_clamps_initial_value() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let counter = use_counter_with_options(0, UseCounterOptions::with_range(1, 10));
+        {
+            let counter = counter.clone();
+            use_effect_once(move || {
+                counter.increase();
+                counter.reset();
+                || ()
+            });
+        }
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">{*counter}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "1");
+}
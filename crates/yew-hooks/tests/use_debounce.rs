@@ -0,0 +1,108 @@
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+mod common;
+
+use common::obtain_result;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+use yew_hooks::{
+    use_debounce, use_debounce_with_options, use_effect_once, use_throttle, UseDebounceOptions,
+};
+
+#[derive(Properties)]
+struct Props {
+    token: Rc<()>,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.token, &other.token)
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_debounce_drops_after_unmount() {
+    #[function_component]
+    fn TestComponent(props: &Props) -> Html {
+        let fired = use_state(|| 0);
+        let debounce = {
+            let fired = fired.clone();
+            let token = props.token.clone();
+            use_debounce_with_options(
+                move || {
+                    let _ = &token;
+                    fired.set(*fired + 1);
+                },
+                10,
+                UseDebounceOptions::with_max_wait(20),
+            )
+        };
+        let throttle = {
+            let token = props.token.clone();
+            use_throttle(move || drop(token.clone()), 10)
+        };
+        {
+            let debounce = debounce.clone();
+            let throttle = throttle.clone();
+            use_effect_once(move || {
+                debounce.run();
+                throttle.run();
+                throttle.run();
+                || ()
+            });
+        }
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">{*fired}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    let token = Rc::new(());
+    let app = yew::Renderer::<TestComponent>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        Props {
+            token: token.clone(),
+        },
+    )
+    .render();
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result().as_str(), "1");
+
+    app.destroy();
+    sleep(Duration::ZERO).await;
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[wasm_bindgen_test]
+async fn use_debounce_waits_from_mount() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let fired = use_state(|| 0);
+        {
+            let fired = fired.clone();
+            use_debounce(move || fired.set(*fired + 1), 10);
+        }
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">{*fired}</div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result().as_str(), "1");
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+mod common;
+
+use common::obtain_result;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+use yew_hooks::{use_effect_once, use_state_machine, StateMachine};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Light {
+    Off,
+    On,
+}
+
+#[wasm_bindgen_test]
+async fn use_state_machine_logs_self_transitions() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let entered = use_mut_ref(|| 0);
+        let light = {
+            let entered = entered.clone();
+            use_state_machine(
+                Light::Off,
+                StateMachine::new(|_, event: &Light| Some(event.clone()))
+                    .on_entry(Light::On, move |_| *entered.borrow_mut() += 1),
+            )
+        };
+
+        {
+            let light = light.clone();
+            use_effect_once(move || {
+                light.send(Light::On);
+                // A self-transition is logged without running entry actions again.
+                light.send(Light::On);
+                || ()
+            });
+        }
+
+        html! {
+            <div>
+                {"Test Output: "}
+                <div id="result">
+                    { format!("{:?} {} {}", *light, light.log().len(), *entered.borrow()) }
+                </div>
+                {"\n"}
+            </div>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "On 2 1");
+}
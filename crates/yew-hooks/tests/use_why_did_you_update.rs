@@ -0,0 +1,134 @@
+#![cfg(feature = "why-did-you-update")]
+
+use std::cell::RefCell;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+mod common;
+
+use common::obtain_result;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+use yew_hooks::{use_effect_once, use_why_did_you_update};
+
+thread_local! {
+    static MESSAGES: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// A logger keeping the messages, to check what the hook logs.
+struct CaptureLogger;
+
+impl log::Log for CaptureLogger {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        MESSAGES.with(|messages| messages.borrow_mut().push(record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger;
+
+/// Capture the log messages starting with `prefix`.
+fn messages(prefix: &str) -> Vec<String> {
+    MESSAGES.with(|messages| {
+        messages
+            .borrow()
+            .iter()
+            .filter(|message| message.starts_with(prefix))
+            .cloned()
+            .collect()
+    })
+}
+
+fn init_logger() {
+    // The logger can only be set once, other tests may have set it already.
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Debug);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Props {
+    name: String,
+    age: u32,
+}
+
+#[wasm_bindgen_test]
+async fn use_why_did_you_update_logs_changed_fields() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let name = use_state(|| "a".to_string());
+        use_why_did_you_update(
+            "Profile".to_string(),
+            Props {
+                name: (*name).clone(),
+                age: 1,
+            },
+        );
+
+        {
+            let name = name.clone();
+            use_effect_once(move || {
+                name.set("b".to_string());
+                || ()
+            });
+        }
+
+        html! {
+            <div id="result">{ &*name }</div>
+        }
+    }
+
+    init_logger();
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "b");
+    assert_eq!(
+        messages("Profile updated"),
+        ["Profile updated: name: \"a\" -> \"b\""]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn use_why_did_you_update_logs_whole_values_on_one_line() {
+    #[function_component]
+    fn TestComponent() -> Html {
+        let value = use_state(|| Some(1));
+        use_why_did_you_update("Value".to_string(), *value);
+
+        {
+            let value = value.clone();
+            use_effect_once(move || {
+                value.set(None);
+                || ()
+            });
+        }
+
+        html! {
+            <div id="result">{ format!("{:?}", *value) }</div>
+        }
+    }
+
+    init_logger();
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "None");
+    assert_eq!(
+        messages("Value updated"),
+        ["Value updated: Some(1) -> None"]
+    );
+}
@@ -25,15 +25,25 @@ pub fn home() -> Html {
                         <li><Link<AppRoute> to={AppRoute::UsePrevious} classes="text-emerald-800 underline">{ "use_previous" }</Link<AppRoute>> { " - returns the previous immutable ref to state or props." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseList} classes="text-emerald-800 underline">{ "use_list" }</Link<AppRoute>> { " - tracks state of a list." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseVirtualList} classes="text-emerald-800 underline">{ "use_virtual_list" }</Link<AppRoute>> { " - provides virtual scrolling for large lists." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseMap} classes="text-emerald-800 underline">{ "use_map" }</Link<AppRoute>> { " - tracks state of a hash map." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseSet} classes="text-emerald-800 underline">{ "use_set" }</Link<AppRoute>> { " - tracks state of a hash set." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseQueue} classes="text-emerald-800 underline">{ "use_queue" }</Link<AppRoute>> { " - tracks state of a queue." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseMap} classes="text-emerald-800 underline">{ "use_map" }</Link<AppRoute>> { " - tracks state of a hash map, or a sorted or insertion-ordered map with use_map_with_backend." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseSet} classes="text-emerald-800 underline">{ "use_set" }</Link<AppRoute>> { " - tracks state of a hash set, or a sorted set with use_set_with_backend." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseQueue} classes="text-emerald-800 underline">{ "use_queue" }</Link<AppRoute>> { " - tracks state of a queue, optionally bounded as a ring buffer." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseRafState} classes="text-emerald-800 underline">{ "use_raf_state" }</Link<AppRoute>> { " - creates set method which only updates after requestAnimationFrame." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseStatePtrEq} classes="text-emerald-800 underline">{ "use_state_ptr_eq" }</Link<AppRoute>> { " - similar to use_state_eq, but checks two Rcs' pointers of allocation." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseRendersCount} classes="text-emerald-800 underline">{ "use_renders_count" }</Link<AppRoute>> { " - counts component renders." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseDefault} classes="text-emerald-800 underline">{ "use_default" }</Link<AppRoute>> { " - returns the default value when state is None." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseDebounceState} classes="text-emerald-800 underline">{ "use_debounce_state" }</Link<AppRoute>> { " - debounces state." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseThrottleState} classes="text-emerald-800 underline">{ "use_throttle_state" }</Link<AppRoute>> { " - throttles state." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UsePersistedList} classes="text-emerald-800 underline" >{ "use_persisted_list" }</Link<AppRoute>> { " - tracks a list persisted in local or session storage, along with use_persisted_map, use_persisted_set and use_persisted_queue hooks." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseHistoryState} classes="text-emerald-800 underline" >{ "use_history_state" }</Link<AppRoute>> { " - tracks a value with undo and redo history." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseCycle} classes="text-emerald-800 underline" >{ "use_cycle" }</Link<AppRoute>> { " - cycles through a list of values, wrapping around or clamped." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseStateMachine} classes="text-emerald-800 underline" >{ "use_state_machine" }</Link<AppRoute>> { " - manages the state of a UI flow as a state machine with enum states and events." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseAtom} classes="text-emerald-800 underline" >{ "use_atom" }</Link<AppRoute>> { " - shares state between components with atoms, along with use_atom_value, use_set_atom hooks and derived selectors." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseForm} classes="text-emerald-800 underline" >{ "use_form" }</Link<AppRoute>> { " - manages a form with field bindings, sync and async validation, field arrays and submission." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseHistoryOf} classes="text-emerald-800 underline" >{ "use_history_of" }</Link<AppRoute>> { " - tracks the last distinct values of state or props with timestamps, along with use_previous_distinct hook." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UsePriorityQueue} classes="text-emerald-800 underline" >{ "use_priority_queue" }</Link<AppRoute>> { " - tracks a priority queue with a custom ordering." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseSelection} classes="text-emerald-800 underline" >{ "use_selection" }</Link<AppRoute>> { " - tracks the selected keys of a list or table with range, select all and keyboard selection." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseDebouncedValue} classes="text-emerald-800 underline" >{ "use_debounced_value" }</Link<AppRoute>> { " - returns a debounced copy of a value, along with use_throttled_value hook." }</li>
                     </ul>
 
                     <h2 class="text-2xl font-bold">{ "Side-effects" }</h2>
@@ -45,13 +55,17 @@ pub fn home() -> Html {
                         <li><Link<AppRoute> to={AppRoute::UseFavicon} classes="text-emerald-800 underline" >{ "use_favicon" }</Link<AppRoute>> { " - sets favicon of the page." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseLocalStorage} classes="text-emerald-800 underline" >{ "use_local_storage" }</Link<AppRoute>> { " - manages a value in localStorage." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseSessionStorage} classes="text-emerald-800 underline" >{ "use_session_storage" }</Link<AppRoute>> { " - manages a value in sessionStorage." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseCookie} classes="text-emerald-800 underline" >{ "use_cookie" }</Link<AppRoute>> { " - manages browser cookies." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseCookie} classes="text-emerald-800 underline" >{ "use_cookie" }</Link<AppRoute>> { " - manages browser cookies, used along with use_cookies hook." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseBeforeUnload} classes="text-emerald-800 underline" >{ "use_before_unload" }</Link<AppRoute>> { " - shows browser alert when user try to reload or close the page." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseDebounce} classes="text-emerald-800 underline" >{ "use_debounce" }</Link<AppRoute>> { " - debounces a function." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseDebounce} classes="text-emerald-800 underline" >{ "use_debounce" }</Link<AppRoute>> { " - debounces a function, with leading, trailing and max wait options." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseDebounceEffect} classes="text-emerald-800 underline" >{ "use_debounce_effect" }</Link<AppRoute>> { " - debounces an effect." }</li>
-                        <li><Link<AppRoute> to={AppRoute::UseThrottle} classes="text-emerald-800 underline" >{ "use_throttle" }</Link<AppRoute>> { " - throttles a function." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseThrottle} classes="text-emerald-800 underline" >{ "use_throttle" }</Link<AppRoute>> { " - throttles a function, with leading and trailing options." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseThrottleEffect} classes="text-emerald-800 underline" >{ "use_throttle_effect" }</Link<AppRoute>> { " - throttles an effect." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseClipboard} classes="text-emerald-800 underline" >{ "use_clipboard" }</Link<AppRoute>> { " - reads from or writes to clipboard for text/bytes." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseIndexedDb} classes="text-emerald-800 underline" >{ "use_indexed_db" }</Link<AppRoute>> { " - opens an IndexedDB database, used along with use_idb_value and use_idb_query hooks." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseEncryptedStorage} classes="text-emerald-800 underline" >{ "use_encrypted_storage" }</Link<AppRoute>> { " - manages a value in localStorage, encrypted with AES-GCM using Web Crypto." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseStorage} classes="text-emerald-800 underline" >{ "use_storage" }</Link<AppRoute>> { " - manages a value in a generic storage backend, with namespaced keys, used along with use_storage_keys hook." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseAsyncEffect} classes="text-emerald-800 underline" >{ "use_async_effect" }</Link<AppRoute>> { " - runs an async effect when dependencies change, with cancellation and async cleanup." }</li>
                     </ul>
 
                     <h2 class="text-2xl font-bold">{ "Lifecycles" }</h2>
@@ -65,6 +79,9 @@ pub fn home() -> Html {
                         <li><Link<AppRoute> to={AppRoute::UseEvent} classes="text-emerald-800 underline">{ "use_event" }</Link<AppRoute>> { " - subscribe to events." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseEffectUpdate} classes="text-emerald-800 underline">{ "use_effect_update" }</Link<AppRoute>> { " - runs an effect only on updates." }</li>
                         <li><Link<AppRoute> to={AppRoute::UseLogger} classes="text-emerald-800 underline">{ "use_logger" }</Link<AppRoute>> { " - logs in console as component goes through life cycles." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseEffectWithCmp} classes="text-emerald-800 underline" >{ "use_effect_with_cmp" }</Link<AppRoute>> { " - runs an effect when dependencies change by a custom comparison, along with use_memo_with_cmp hook." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseWhyDidYouUpdate} classes="text-emerald-800 underline" >{ "use_why_did_you_update" }</Link<AppRoute>> { " - logs which props changed when a component re-renders, with the why-did-you-update feature." }</li>
+                        <li><Link<AppRoute> to={AppRoute::UseRenderProfiler} classes="text-emerald-800 underline" >{ "use_render_profiler" }</Link<AppRoute>> { " - profiles render durations with the Performance API." }</li>
                     </ul>
 
                     <h2 class="text-2xl font-bold">{ "Animations" }</h2>
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_async_effect` demo
+#[function_component]
+pub fn UseAsyncEffect() -> Html {
+    let room = use_state(|| "general");
+    let status = use_state(String::new);
+    let log = use_list(vec![]);
+
+    {
+        let status = status.clone();
+        let log = log.clone();
+        use_async_effect(*room, move |room, abort| {
+            let room = *room;
+            async move {
+                status.set(format!("Connecting to {}...", room));
+                // Dropped here if another room is picked within 2 seconds.
+                sleep(Duration::from_millis(2000)).await;
+                status.set(format!("Connected to {}", room));
+
+                move || async move {
+                    sleep(Duration::from_millis(500)).await;
+                    log.push(format!("Left {} (aborted: {})", room, abort.is_aborted()));
+                }
+            }
+        });
+    }
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    {
+                        for ["general", "random", "help"].into_iter().map(|name| {
+                            let disabled = *room == name;
+                            let room = room.clone();
+                            let onclick = Callback::from(move |_| room.set(name));
+                            html! { <Button {onclick} {disabled}>{ "#" }{ name }</Button> }
+                        })
+                    }
+                    <p>{ &*status }</p>
+                    {
+                        for log.current().iter().map(|line| html! { <p>{ line }</p> })
+                    }
+                </div>
+            </header>
+        </div>
+    }
+}
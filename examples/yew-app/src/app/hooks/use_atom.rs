@@ -0,0 +1,74 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+static CART: Atom<Vec<String>> = Atom::new(Vec::new);
+static CART_SIZE: Selector<usize> = Selector::new(|get| get.get(&CART).len());
+
+#[function_component]
+fn Products() -> Html {
+    let cart = use_atom(&CART);
+
+    html! {
+        <div class="space-x-4 space-y-4">
+            {
+                for ["Earth", "Mars", "Jupiter"].into_iter().map(|planet| {
+                    let cart = cart.clone();
+                    let onclick = Callback::from(move |_| cart.update(|cart| {
+                        let mut cart = cart.clone();
+                        cart.push(planet.to_string());
+                        cart
+                    }));
+                    html! { <Button {onclick}>{ "Add " }{ planet }</Button> }
+                })
+            }
+            <p>
+                <b>{ "Cart: " }</b>
+                { cart.join(", ") }
+            </p>
+        </div>
+    }
+}
+
+#[function_component]
+fn CartBadge() -> Html {
+    // Only re-renders when the number of items changes.
+    let size = use_atom_value(&CART_SIZE);
+    let renders = use_renders_count();
+
+    html! {
+        <p>
+            <b>{ "Items in cart: " }</b>
+            { *size }
+            { format!(" (badge rendered {} times)", renders) }
+        </p>
+    }
+}
+
+#[function_component]
+fn ClearCart() -> Html {
+    // Does not re-render when the cart changes.
+    let set_cart = use_set_atom(&CART);
+    let onclick = Callback::from(move |_| set_cart.emit(vec![]));
+
+    html! {
+        <Button {onclick}>{ "Clear cart" }</Button>
+    }
+}
+
+/// `use_atom` demo
+#[function_component]
+pub fn UseAtom() -> Html {
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Products />
+                    <CartBadge />
+                    <ClearCart />
+                </div>
+            </header>
+        </div>
+    }
+}
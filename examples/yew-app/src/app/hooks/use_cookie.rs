@@ -7,6 +7,7 @@ use crate::components::ui::button::Button;
 #[function_component]
 pub fn UseCookie() -> Html {
     let cookie = use_cookie::<String>("example_cookie".to_string());
+    let cookies = use_cookies();
 
     let on_set_string = {
         let cookie = cookie.clone();
@@ -36,16 +37,18 @@ pub fn UseCookie() -> Html {
         Callback::from(move |_| {
             use yew_hooks::{CookieAttributes, SameSite};
 
-            // Set cookie to expire in 1 hour (using max_age)
-            let attributes = CookieAttributes {
-                max_age: Some(3600), // 1 hour in seconds
-                path: Some("/".to_string()),
-                secure: false, // Set to true in production with HTTPS
-                same_site: Some(SameSite::Lax),
-                ..Default::default()
-            };
+            // Set cookie to expire in 1 hour
+            let attributes = CookieAttributes::default()
+                .with_expires(js_sys::Date::now() + 60.0 * 60.0 * 1000.0)
+                .with_path("/")
+                .with_secure(false) // Set to true in production with HTTPS
+                .with_same_site(SameSite::Lax);
 
-            cookie.set_with_attributes("Cookie with attributes".to_string(), attributes);
+            if let Err(e) =
+                cookie.try_set_with_attributes("Cookie with attributes".to_string(), attributes)
+            {
+                log::warn!("Invalid cookie attributes: {}", e);
+            }
         })
     };
 
@@ -82,6 +85,19 @@ pub fn UseCookie() -> Html {
                     </div>
                 </div>
 
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-lg p-6 mb-8">
+                    <h2 class="text-2xl font-semibold text-gray-900 dark:text-gray-100 mb-4">
+                        { "All Cookies" }
+                    </h2>
+                    <ul class="font-mono text-sm break-all">
+                        {
+                            for cookies.iter().map(|(key, value)| html! {
+                                <li><b>{ key }</b>{ ": " }{ value }</li>
+                            })
+                        }
+                    </ul>
+                </div>
+
                 <div class="bg-white dark:bg-gray-800 rounded-lg shadow-lg p-6">
                     <h2 class="text-2xl font-semibold text-gray-900 dark:text-gray-100 mb-6">
                         { "Cookie Operations" }
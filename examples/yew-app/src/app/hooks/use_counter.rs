@@ -7,6 +7,13 @@ use crate::components::ui::button::Button;
 #[function_component]
 pub fn UseCounter() -> Html {
     let counter = use_counter(0);
+    let page = use_counter_with_options(
+        1u32,
+        UseCounterOptions {
+            mode: BoundaryMode::Wrap,
+            ..UseCounterOptions::with_range(1, 5)
+        },
+    );
 
     let onincrease = {
         let counter = counter.clone();
@@ -32,6 +39,14 @@ pub fn UseCounter() -> Html {
         let counter = counter.clone();
         Callback::from(move |_| counter.reset())
     };
+    let onprev = {
+        let page = page.clone();
+        Callback::from(move |_| page.decrease())
+    };
+    let onnext = {
+        let page = page.clone();
+        Callback::from(move |_| page.increase())
+    };
 
     html! {
         <div class="container">
@@ -47,6 +62,12 @@ pub fn UseCounter() -> Html {
                         <b>{ "Current value: " }</b>
                         { *counter }
                     </p>
+                    <Button onclick={onprev}>{ "Previous page" }</Button>
+                    <Button onclick={onnext}>{ "Next page" }</Button>
+                    <p>
+                        <b>{ "Page (wraps around): " }</b>
+                        { *page } { " / 5" }
+                    </p>
                 </div>
             </header>
         </div>
@@ -0,0 +1,52 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_cycle` demo
+#[function_component]
+pub fn UseCycle() -> Html {
+    let view = use_cycle(vec!["List", "Grid", "Table"]);
+    let step = use_cycle_with_options(
+        vec!["Cart", "Shipping", "Payment", "Review"],
+        UseCycleOptions::clamp(),
+    );
+
+    let onview = {
+        let view = view.clone();
+        Callback::from(move |_| view.next())
+    };
+    let onprev = {
+        let step = step.clone();
+        Callback::from(move |_| step.prev())
+    };
+    let onnext = {
+        let step = step.clone();
+        Callback::from(move |_| step.next())
+    };
+    let onreset = {
+        let step = step.clone();
+        Callback::from(move |_| step.reset())
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onview}>{ "Next view mode" }</Button>
+                    <p>
+                        <b>{ "View mode (wraps around): " }</b>
+                        { *view }
+                    </p>
+                    <Button onclick={onprev} disabled={step.index() == 0}>{ "Back" }</Button>
+                    <Button onclick={onnext} disabled={step.index() + 1 == step.values().len()}>{ "Continue" }</Button>
+                    <Button onclick={onreset}>{ "Reset" }</Button>
+                    <p>
+                        <b>{ "Checkout step (clamped): " }</b>
+                        { *step } { format!(" ({}/{})", step.index() + 1, step.values().len()) }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
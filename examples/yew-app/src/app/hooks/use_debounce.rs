@@ -15,12 +15,14 @@ pub fn UseDebounce() -> Html {
         let status = status.clone();
         let value = value.clone();
         let debounced_value = debounced_value.clone();
-        use_debounce(
+        // Keeps up with long bursts of typing at least every 5 seconds.
+        use_debounce_with_options(
             move || {
                 debounced_value.set((*value).clone());
                 status.set("Typing stopped".to_string());
             },
             2000,
+            UseDebounceOptions::with_max_wait(5000),
         )
     };
 
@@ -36,6 +38,11 @@ pub fn UseDebounce() -> Html {
         })
     };
 
+    let onflush = {
+        let debounce = debounce.clone();
+        Callback::from(move |_| debounce.flush())
+    };
+
     let onclick = { Callback::from(move |_| debounce.cancel()) };
 
     html! {
@@ -43,6 +50,7 @@ pub fn UseDebounce() -> Html {
             <header class="mt-24 text-xl text-center">
                 <div class="space-x-4 space-y-4">
                     <input class="flex h-10 w-full rounded-md border border-input bg-transparent px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50" type="text" value={(*value).clone()} placeholder="Debounced input" {oninput}/>
+                    <Button onclick={onflush}>{ "Flush debounce" }</Button>
                     <Button {onclick}>{ "Cancel debounce" }</Button>
                     <p>{&*status}</p>
                     <p>
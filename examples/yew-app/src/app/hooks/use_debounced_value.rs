@@ -0,0 +1,57 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+#[derive(PartialEq, Properties)]
+struct ResultsProps {
+    query: String,
+}
+
+#[function_component]
+fn Results(props: &ResultsProps) -> Html {
+    // Only follows the query once typing stops.
+    let query = use_debounced_value(props.query.clone(), 1000);
+    let renders = use_renders_count();
+
+    html! {
+        <p>
+            <b>{ "Searching for: " }</b>
+            { query }
+            { format!(" (rendered {} times)", renders) }
+        </p>
+    }
+}
+
+/// `use_debounced_value` demo
+#[function_component]
+pub fn UseDebouncedValue() -> Html {
+    let query = use_state(String::new);
+    let scroll = use_window_scroll();
+    let throttled_scroll = use_throttled_value(scroll, 500);
+
+    let oninput = {
+        let query = query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            query.set(input.value());
+        })
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <input class="flex h-10 w-full rounded-md border border-input bg-transparent px-3 py-2 text-sm ring-offset-background placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2" type="text" value={(*query).clone()} placeholder="Search" {oninput}/>
+                    <p>
+                        <b>{ "Query: " }</b> {&*query}
+                    </p>
+                    <Results query={(*query).clone()} />
+                    <p>
+                        <b>{ "Throttled scroll Y: " }</b> { throttled_scroll.1 }
+                    </p>
+                    <div style="height: 2000px;"></div>
+                </div>
+            </header>
+        </div>
+    }
+}
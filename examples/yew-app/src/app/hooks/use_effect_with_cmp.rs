@@ -0,0 +1,74 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(Clone)]
+struct Draft {
+    version: u32,
+    words: Vec<&'static str>,
+}
+
+/// `use_effect_with_cmp` demo
+#[function_component]
+pub fn UseEffectWithCmp() -> Html {
+    let draft = use_state(|| {
+        Rc::new(Draft {
+            version: 0,
+            words: vec!["Hello"],
+        })
+    });
+    let saves = use_counter(0);
+
+    // Saves only when the version changes, not on every edit.
+    {
+        let saves = saves.clone();
+        use_effect_with_cmp(
+            (*draft).clone(),
+            |a, b| a.version == b.version,
+            move |_| saves.increase(),
+        );
+    }
+    // Counts again only when the draft is replaced, without comparing every word.
+    let count = use_memo_with_cmp((*draft).clone(), Rc::ptr_eq, |draft| draft.words.len());
+
+    let onedit = {
+        let draft = draft.clone();
+        Callback::from(move |_| {
+            let mut next = (**draft).clone();
+            next.words.push("world");
+            draft.set(Rc::new(next));
+        })
+    };
+    let onpublish = {
+        let draft = draft.clone();
+        Callback::from(move |_| {
+            let mut next = (**draft).clone();
+            next.version += 1;
+            draft.set(Rc::new(next));
+        })
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onedit}>{ "Edit" }</Button>
+                    <Button onclick={onpublish}>{ "Publish version" }</Button>
+                    <p>{ draft.words.join(" ") }</p>
+                    <p>
+                        <b>{ "Version: " }</b> { draft.version }
+                    </p>
+                    <p>
+                        <b>{ "Words: " }</b> { *count }
+                    </p>
+                    <p>
+                        <b>{ "Saved times: " }</b> { *saves }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
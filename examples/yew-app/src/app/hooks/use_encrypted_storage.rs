@@ -0,0 +1,54 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_encrypted_storage` demo
+#[function_component]
+pub fn UseEncryptedStorage() -> Html {
+    let storage = use_encrypted_storage::<String>(
+        "encrypted_token".to_string(),
+        EncryptionKey::Passphrase {
+            passphrase: "correct horse battery staple".to_string(),
+            salt: b"yew-hooks-demo".to_vec(),
+            iterations: 100_000,
+        },
+    );
+
+    let onclick = {
+        let storage = storage.clone();
+        Callback::from(move |_| storage.set("jwt_token".to_string()))
+    };
+    let ondelete = {
+        let storage = storage.clone();
+        Callback::from(move |_| storage.delete())
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onclick} disabled={*storage.loading}>{ "Save token" }</Button>
+                    <Button onclick={ondelete}>{ "Delete token" }</Button>
+                    <p>
+                        <b>{ "Decrypted token: " }</b>
+                        {
+                            if *storage.loading {
+                                "Decrypting...".to_string()
+                            } else {
+                                (*storage).clone().unwrap_or_default()
+                            }
+                        }
+                    </p>
+                    {
+                        if let Some(error) = &*storage.error {
+                            html! { <p>{ error.to_string() }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(Clone, PartialEq, Default)]
+struct SignUp {
+    username: String,
+    password: String,
+    emails: Vec<String>,
+}
+
+/// `use_form` demo
+#[function_component]
+pub fn UseForm() -> Html {
+    let submitted = use_state(|| None);
+    let form = use_form_with_options(
+        SignUp {
+            emails: vec![String::new()],
+            ..Default::default()
+        },
+        UseFormOptions::new()
+            .validator("username", |value, _| {
+                if value.len() < 3 {
+                    Err("At least 3 characters".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .async_validator("username", |value, _| async move {
+                // Pretend to ask a server whether the username is available.
+                sleep(Duration::from_millis(800)).await;
+                if value == "admin" {
+                    Err("Username taken".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validator("password", |value, _| {
+                if value.len() < 8 {
+                    Err("At least 8 characters".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validator("emails", |value, _| {
+                if value.contains('@') {
+                    Ok(())
+                } else {
+                    Err("Invalid email".to_string())
+                }
+            }),
+    );
+    let username = form.register("username", |values| &mut values.username);
+    let password = form.register("password", |values| &mut values.password);
+    let emails = form.field_array("emails", |values| &mut values.emails);
+
+    let onsubmit = {
+        let submitted = submitted.clone();
+        form.handle_submit(move |values: SignUp| {
+            let submitted = submitted.clone();
+            async move {
+                sleep(Duration::from_millis(1000)).await;
+                submitted.set(Some(values.username));
+            }
+        })
+    };
+    let onadd = {
+        let emails = form.field_array("emails", |values| &mut values.emails);
+        Callback::from(move |_| emails.append(String::new()))
+    };
+    let onreset = {
+        let form = form.clone();
+        Callback::from(move |_| form.reset())
+    };
+
+    let field_class = |field: &FormField| {
+        if field.touched && field.error.is_some() {
+            "border border-red-600 p-1"
+        } else {
+            "border p-1"
+        }
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <form class="space-x-4 space-y-4" {onsubmit}>
+                    <p>
+                        <input placeholder="Username" class={field_class(&username)} value={username.value.clone()} oninput={username.oninput.clone()} onblur={username.onblur.clone()} />
+                        { if username.validating { " Checking..." } else { "" } }
+                        { if username.touched { username.error.clone().unwrap_or_default() } else { String::new() } }
+                    </p>
+                    <p>
+                        <input type="password" placeholder="Password" class={field_class(&password)} value={password.value.clone()} oninput={password.oninput.clone()} onblur={password.onblur.clone()} />
+                        { if password.touched { password.error.clone().unwrap_or_default() } else { String::new() } }
+                    </p>
+                    {
+                        for emails.fields().into_iter().enumerate().map(|(index, email)| {
+                            let onremove = {
+                                let emails = form.field_array("emails", |values| &mut values.emails);
+                                Callback::from(move |_| emails.remove(index))
+                            };
+                            html! {
+                                <p>
+                                    <input placeholder="Email" class={field_class(&email)} value={email.value.clone()} oninput={email.oninput.clone()} onblur={email.onblur.clone()} />
+                                    <Button onclick={onremove} disabled={emails.len() == 1}>{ "Remove" }</Button>
+                                    { if email.touched { email.error.clone().unwrap_or_default() } else { String::new() } }
+                                </p>
+                            }
+                        })
+                    }
+                    <Button onclick={onadd}>{ "Add email" }</Button>
+                    <Button onclick={onreset} disabled={!form.is_dirty()}>{ "Reset" }</Button>
+                    <Button button_type="submit" disabled={form.is_submitting()}>
+                        { "Sign up" }
+                        { if form.is_submitting() { "..." } else { "" } }
+                    </Button>
+                    <p>
+                        <b>{ "Submit attempts: " }</b>
+                        { form.submit_count() }
+                    </p>
+                    if let Some(username) = &*submitted {
+                        <p>{ "Signed up " }{ username }</p>
+                    }
+                </form>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,57 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_history_of` demo
+#[function_component]
+pub fn UseHistoryOf() -> Html {
+    let state = use_state(|| 0);
+    let history = use_history_of(*state, 5);
+    // Only advances when the value crosses a multiple of 10.
+    let previous_ten = use_previous_distinct(*state, |a: &i32, b: &i32| a / 10 == b / 10);
+
+    let onincrease = {
+        let state = state.clone();
+        Callback::from(move |_| state.set(*state + 1))
+    };
+    let onincrease_ten = {
+        let state = state.clone();
+        Callback::from(move |_| state.set(*state + 10))
+    };
+    let onsame = {
+        let state = state.clone();
+        Callback::from(move |_| state.set(*state))
+    };
+
+    let age = |timestamp: f64| ((js_sys::Date::now() - timestamp) / 1000.0).round();
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onincrease}>{ "Increase" }</Button>
+                    <Button onclick={onincrease_ten}>{ "Increase by 10" }</Button>
+                    <Button onclick={onsame}>{ "Set same value" }</Button>
+                    <p>
+                        <b>{ "Last 5 distinct values: " }</b>
+                        {
+                            for history.iter().map(|recorded| html! {
+                                <span>{ format!("{} ({}s ago) ", recorded.value, age(recorded.timestamp)) }</span>
+                            })
+                        }
+                    </p>
+                    <p>
+                        <b>{ "Previous value in another ten: " }</b>
+                        {
+                            previous_ten
+                                .previous()
+                                .map(|previous| previous.value.to_string())
+                                .unwrap_or_else(|| "None".to_string())
+                        }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,55 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_history_state` demo
+#[function_component]
+pub fn UseHistoryState() -> Html {
+    let text = use_history_state_with_options(
+        String::new(),
+        UseHistoryStateOptions {
+            capacity: Some(100),
+            checkpoint_millis: 500,
+        },
+    );
+
+    let oninput = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            text.set(input.value());
+        })
+    };
+    let onundo = {
+        let text = text.clone();
+        Callback::from(move |_| text.undo())
+    };
+    let onredo = {
+        let text = text.clone();
+        Callback::from(move |_| text.redo())
+    };
+    let onclear = {
+        let text = text.clone();
+        Callback::from(move |_| text.clear_history())
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <input
+                        class="flex h-10 w-full rounded-md border border-input bg-transparent px-3 py-2 text-sm"
+                        placeholder="Type, pausing between words"
+                        value={text.current().clone()}
+                        {oninput}
+                    />
+                    <Button onclick={onundo} disabled={!text.can_undo()}>{ "Undo" }</Button>
+                    <Button onclick={onredo} disabled={!text.can_redo()}>{ "Redo" }</Button>
+                    <Button onclick={onclear}>{ "Clear history" }</Button>
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,68 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_indexed_db` demo
+#[function_component]
+pub fn UseIndexedDb() -> Html {
+    let db = use_indexed_db("yew-hooks-demo".to_string(), 1, |upgrade| {
+        if upgrade.old_version < 1 {
+            let _ = upgrade.db.create_object_store("notes");
+        }
+    });
+    let draft = use_idb_value::<String, _>(&db, "notes".to_string(), "draft");
+    let notes = use_idb_query::<String>(&db, "notes".to_string(), IdbRange::All);
+
+    let oninput = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            draft.set(input.value());
+        })
+    };
+    let ondelete = {
+        let draft = draft.clone();
+        Callback::from(move |_| draft.delete())
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <p>
+                        <b>{ "Database: " }</b>
+                        {
+                            if db.loading {
+                                "Opening...".to_string()
+                            } else if let Some(error) = &db.error {
+                                error.to_string()
+                            } else {
+                                "Open".to_string()
+                            }
+                        }
+                    </p>
+                    <input
+                        class="flex h-10 w-full rounded-md border border-input bg-transparent px-3 py-2 text-sm"
+                        placeholder="Draft, persisted in IndexedDB"
+                        value={(*draft).clone().unwrap_or_default()}
+                        {oninput}
+                    />
+                    <Button onclick={ondelete}>{ "Delete draft" }</Button>
+                    <p>
+                        <b>{ "Records in store: " }</b>
+                        { notes.len() }
+                    </p>
+                    {
+                        if let Some(error) = &*draft.error {
+                            html! { <p>{ error.to_string() }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -64,6 +64,24 @@ pub fn UseList() -> Html {
         let list = list.clone();
         Callback::from(move |_| list.clear())
     };
+    let onmove = {
+        let list = list.clone();
+        Callback::from(move |_| {
+            let len = list.current().len();
+            if len > 1 {
+                list.move_item(0, len - 1);
+            }
+        })
+    };
+    let onbatch = {
+        let list = list.clone();
+        Callback::from(move |_| {
+            list.batch(|list| {
+                list.iter_mut().for_each(|x| *x *= 2);
+                list.truncate(3);
+            })
+        })
+    };
 
     html! {
         <div class="container">
@@ -82,6 +100,8 @@ pub fn UseList() -> Html {
                     <Button onclick={onsortdesc}>{ "Sort desc" }</Button>
                     <Button onclick={onswap}>{ "Swap position 0 and 1" }</Button>
                     <Button onclick={onclear}>{ "Clear all" }</Button>
+                    <Button onclick={onmove}>{ "Move first to last" }</Button>
+                    <Button onclick={onbatch}>{ "Double and keep first 3" }</Button>
                     <p>
                         <b>{ "Current list: " }</b>
                     </p>
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use yew::prelude::*;
 use yew_hooks::prelude::*;
@@ -8,7 +8,7 @@ use crate::components::ui::button::Button;
 /// `use_map` demo
 #[function_component]
 pub fn UseMap() -> Html {
-    let map = use_map(HashMap::from([
+    let map = use_map_with_backend(BTreeMap::from([
         ("Mercury", 0.4),
         ("Venus", 0.7),
         ("Earth", 1.0),
@@ -17,7 +17,7 @@ pub fn UseMap() -> Html {
 
     let onset = {
         let map = map.clone();
-        Callback::from(move |_| map.set(HashMap::from([("Moon", 0.8), ("Earth", 1.0)])))
+        Callback::from(move |_| map.set(BTreeMap::from([("Moon", 0.8), ("Earth", 1.0)])))
     };
     let oninsert = {
         let map = map.clone();
@@ -39,6 +39,10 @@ pub fn UseMap() -> Html {
         let map = map.clone();
         Callback::from(move |_| map.retain(|_k, v| v > &mut 1.0))
     };
+    let onupsert = {
+        let map = map.clone();
+        Callback::from(move |_| map.upsert_with("Saturn", || 9.0, |v| *v += 0.5))
+    };
     let onclear = {
         let map = map.clone();
         Callback::from(move |_| map.clear())
@@ -53,6 +57,7 @@ pub fn UseMap() -> Html {
                     <Button onclick={onupdate}>{ "Update" }</Button>
                     <Button onclick={onremove}>{ "Remove" }</Button>
                     <Button onclick={onretain}>{ "Retain" }</Button>
+                    <Button onclick={onupsert}>{ "Upsert Saturn" }</Button>
                     <Button onclick={onclear}>{ "Clear all" }</Button>
                     <p>
                         <b>{ "Current map: " }</b>
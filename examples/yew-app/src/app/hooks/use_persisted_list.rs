@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+const PLANETS: [&str; 4] = ["Mercury", "Venus", "Earth", "Mars"];
+
+/// `use_persisted_list` demo
+#[function_component]
+pub fn UsePersistedList() -> Html {
+    let recent = use_persisted_list::<LocalStorageBackend, String>("recent".to_string(), vec![]);
+    let favorites =
+        use_persisted_set::<LocalStorageBackend, String>("favorites".to_string(), HashSet::new());
+
+    let planets = PLANETS.iter().map(|planet| {
+        let onvisit = {
+            let recent = recent.clone();
+            Callback::from(move |_| {
+                recent.retain(|item| item != planet);
+                recent.insert(0, planet.to_string());
+            })
+        };
+        let onfavorite = {
+            let favorites = favorites.clone();
+            Callback::from(move |_| {
+                if !favorites.remove(&planet.to_string()) {
+                    let _ = favorites.insert(planet.to_string());
+                }
+            })
+        };
+        let label = if favorites.current().contains(*planet) {
+            "Unfavorite"
+        } else {
+            "Favorite"
+        };
+
+        html! {
+            <p>
+                <Button onclick={onvisit}>{ "Visit " }{ planet }</Button>
+                <Button onclick={onfavorite}>{ label }{ " " }{ planet }</Button>
+            </p>
+        }
+    });
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    { for planets }
+                    <p>
+                        <b>{ "Recently visited: " }</b>
+                        { recent.current().join(", ") }
+                    </p>
+                    <p>
+                        <b>{ "Favorites: " }</b>
+                        { favorites.current().iter().cloned().collect::<Vec<_>>().join(", ") }
+                    </p>
+                    <p>{ "Open this page in another tab to see the changes synced." }</p>
+                </div>
+            </header>
+        </div>
+    }
+}
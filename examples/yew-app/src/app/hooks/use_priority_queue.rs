@@ -0,0 +1,75 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(Clone, PartialEq)]
+struct Toast {
+    message: String,
+    urgent: bool,
+    id: u32,
+}
+
+/// `use_priority_queue` demo
+#[function_component]
+pub fn UsePriorityQueue() -> Html {
+    let jobs = use_priority_queue(vec![(1, "Backup"), (5, "Deploy"), (3, "Test")]);
+    // Urgent toasts first, then the oldest ones.
+    let toasts = use_priority_queue_by(vec![], |a: &Toast, b: &Toast| {
+        a.urgent.cmp(&b.urgent).then(b.id.cmp(&a.id))
+    });
+    let next_id = use_mut_ref(|| 0);
+
+    let onpush = {
+        let jobs = jobs.clone();
+        Callback::from(move |_| jobs.push((4, "Build")))
+    };
+    let onpop = {
+        let jobs = jobs.clone();
+        Callback::from(move |_| {
+            let _ = jobs.pop();
+        })
+    };
+    let ontoast = |urgent: bool| {
+        let toasts = toasts.clone();
+        let next_id = next_id.clone();
+        Callback::from(move |_| {
+            *next_id.borrow_mut() += 1;
+            let id = *next_id.borrow();
+            toasts.push(Toast {
+                message: format!("{} toast #{}", if urgent { "Urgent" } else { "Info" }, id),
+                urgent,
+                id,
+            });
+        })
+    };
+    let ondismiss = {
+        let toasts = toasts.clone();
+        Callback::from(move |_| {
+            let _ = toasts.pop();
+        })
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onpush}>{ "Push (4, Build)" }</Button>
+                    <Button onclick={onpop} disabled={jobs.is_empty()}>{ "Run next job" }</Button>
+                    <p>
+                        <b>{ "Jobs by priority: " }</b>
+                        { jobs.sorted().iter().map(|(priority, name)| format!("{} ({})", name, priority)).collect::<Vec<_>>().join(", ") }
+                    </p>
+                    <Button onclick={ontoast(false)}>{ "Info toast" }</Button>
+                    <Button onclick={ontoast(true)}>{ "Urgent toast" }</Button>
+                    <Button onclick={ondismiss} disabled={toasts.is_empty()}>{ "Dismiss" }</Button>
+                    <p>
+                        <b>{ "Showing: " }</b>
+                        { toasts.peek().map(|toast| toast.message.clone()).unwrap_or_else(|| "None".to_string()) }
+                        { format!(" ({} pending)", toasts.len()) }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
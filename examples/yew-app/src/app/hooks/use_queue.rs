@@ -9,6 +9,8 @@ use crate::components::ui::button::Button;
 #[function_component]
 pub fn UseQueue() -> Html {
     let queue = use_queue(VecDeque::from(["Mercury", "Venus", "Earth", "Mars"]));
+    let recent = use_queue_with_options(VecDeque::new(), UseQueueOptions::with_capacity(3));
+    let counter = use_counter(0);
 
     let onset = {
         let queue = queue.clone();
@@ -20,6 +22,24 @@ pub fn UseQueue() -> Html {
             queue.push_back("Jupiter");
         })
     };
+    let onpush_front = {
+        let queue = queue.clone();
+        Callback::from(move |_| queue.push_front("Sun"))
+    };
+    let onpop_back = {
+        let queue = queue.clone();
+        Callback::from(move |_| {
+            let _ = queue.pop_back();
+        })
+    };
+    let onrecent = {
+        let recent = recent.clone();
+        let counter = counter.clone();
+        Callback::from(move |_| {
+            counter.increase();
+            recent.push_back(format!("Event {}", *counter + 1));
+        })
+    };
     let onpop_front = {
         let queue = queue.clone();
         Callback::from(move |_| {
@@ -42,10 +62,13 @@ pub fn UseQueue() -> Html {
                     <Button onclick={onset}>{ "Set" }</Button>
                     <Button onclick={onpush_back}>{ "Push back" }</Button>
                     <Button onclick={onpop_front}>{ "Pop front" }</Button>
+                    <Button onclick={onpush_front}>{ "Push front" }</Button>
+                    <Button onclick={onpop_back}>{ "Pop back" }</Button>
                     <Button onclick={onretain}>{ "Retain" }</Button>
                     <Button onclick={onclear}>{ "Clear all" }</Button>
                     <p>
                         <b>{ "Current queue: " }</b>
+                        { format!("{} items, front is {}", queue.len(), queue.peek().map(|v| *v).unwrap_or("None")) }
                     </p>
                     {
                         for queue.current().iter().map(|v| {
@@ -54,6 +77,11 @@ pub fn UseQueue() -> Html {
                             }
                         })
                     }
+                    <Button onclick={onrecent}>{ "Add event" }</Button>
+                    <p>
+                        <b>{ "Last 3 events (ring buffer): " }</b>
+                        { recent.current().iter().cloned().collect::<Vec<_>>().join(", ") }
+                    </p>
                 </div>
             </header>
         </div>
@@ -0,0 +1,70 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(PartialEq, Properties)]
+struct ListProps {
+    pub size: usize,
+    pub onstats: Callback<RenderStats>,
+}
+
+#[function_component]
+fn List(props: &ListProps) -> Html {
+    let onstats = props.onstats.clone();
+    use_render_profiler_with_options(
+        "List".to_string(),
+        UseRenderProfilerOptions {
+            onstats: Some(Box::new(move |stats| onstats.emit(stats))),
+            console_table: true,
+            ..Default::default()
+        },
+    );
+
+    html! {
+        <div class="h-48 overflow-auto">
+            { for (0..props.size).map(|i| html! { <p>{ "Item " }{ i }</p> }) }
+        </div>
+    }
+}
+
+/// `use_render_profiler` demo
+#[function_component]
+pub fn UseRenderProfiler() -> Html {
+    let size = use_counter(100);
+    let stats = use_state(RenderStats::default);
+
+    let onincrease = {
+        let size = size.clone();
+        Callback::from(move |_| size.increase_by(1000))
+    };
+    let ondecrease = {
+        let size = size.clone();
+        Callback::from(move |_| size.decrease_by(1000))
+    };
+    let onstats = {
+        let stats = stats.clone();
+        Callback::from(move |next| stats.set(next))
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onincrease}>{ "Add 1000 items" }</Button>
+                    <Button onclick={ondecrease} disabled={*size < 1000}>{ "Remove 1000 items" }</Button>
+                    <p>
+                        <b>{ "Renders: " }</b> { stats.count }
+                    </p>
+                    <p>
+                        { format!("last {:.2}ms, mean {:.2}ms, p95 {:.2}ms, max {:.2}ms", stats.last, stats.mean, stats.p95, stats.max) }
+                    </p>
+                    <List size={*size as usize} {onstats} />
+                    <p>
+                        <b>{ "Please open the browser console to view the table!" }</b>
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,81 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_selection` demo
+#[function_component]
+pub fn UseSelection() -> Html {
+    let planets = use_list(vec![
+        "Mercury", "Venus", "Earth", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune",
+    ]);
+    let selection = use_selection(planets.current().clone());
+
+    let onselect_all = {
+        let selection = selection.clone();
+        Callback::from(move |_| selection.toggle_all())
+    };
+    let oninvert = {
+        let selection = selection.clone();
+        Callback::from(move |_| selection.invert())
+    };
+    let onnone = {
+        let selection = selection.clone();
+        Callback::from(move |_| selection.select_none())
+    };
+    let onremove_last = {
+        let planets = planets.clone();
+        Callback::from(move |_| {
+            let _ = planets.pop();
+        })
+    };
+    let onkeydown = {
+        let selection = selection.clone();
+        Callback::from(move |e: KeyboardEvent| selection.handle_keydown(&e))
+    };
+
+    let header = if selection.is_all_selected() {
+        "[x]"
+    } else if selection.is_indeterminate() {
+        "[-]"
+    } else {
+        "[ ]"
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onselect_all}>{ header }{ " All" }</Button>
+                    <Button onclick={oninvert}>{ "Invert" }</Button>
+                    <Button onclick={onnone}>{ "None" }</Button>
+                    <Button onclick={onremove_last}>{ "Remove last planet" }</Button>
+                    <p>{ "Click, shift-click and ctrl-click the planets, or focus the list and use the arrow keys, shift, space, ctrl+a and escape." }</p>
+                    <ul tabindex="0" class="border p-2 select-none" {onkeydown}>
+                        {
+                            for planets.current().iter().map(|planet| {
+                                let planet = *planet;
+                                let onclick = {
+                                    let selection = selection.clone();
+                                    Callback::from(move |e: MouseEvent| selection.click(planet, &e))
+                                };
+                                let class = classes!(
+                                    "cursor-pointer",
+                                    selection.is_selected(&planet).then_some("bg-emerald-200"),
+                                    (selection.focused() == Some(planet)).then_some("underline"),
+                                );
+                                html! {
+                                    <li {class} {onclick}>{ planet }</li>
+                                }
+                            })
+                        }
+                    </ul>
+                    <p>
+                        <b>{ "Selected: " }</b>
+                        { selection.selected().join(", ") }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
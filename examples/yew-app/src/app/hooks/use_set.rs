@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 use yew::prelude::*;
 use yew_hooks::prelude::*;
@@ -9,6 +9,9 @@ use crate::components::ui::button::Button;
 #[function_component]
 pub fn UseSet() -> Html {
     let set = use_set(HashSet::from(["Mercury", "Venus", "Earth", "Mars"]));
+    // Checked planets render in a stable order.
+    let checked = use_set_with_backend(BTreeSet::from(["Earth"]));
+    let gas_giants = BTreeSet::from(["Jupiter", "Saturn", "Uranus", "Neptune"]);
 
     let onset = {
         let set = set.clone();
@@ -40,6 +43,20 @@ pub fn UseSet() -> Html {
         let set = set.clone();
         Callback::from(move |_| set.clear())
     };
+    let onunion = {
+        let checked = checked.clone();
+        let gas_giants = gas_giants.clone();
+        Callback::from(move |_| checked.union_with(&gas_giants))
+    };
+    let onintersect = {
+        let checked = checked.clone();
+        let gas_giants = gas_giants.clone();
+        Callback::from(move |_| checked.intersect_with(&gas_giants))
+    };
+    let ondifference = {
+        let checked = checked.clone();
+        Callback::from(move |_| checked.difference_with(&gas_giants))
+    };
 
     html! {
         <div class="container">
@@ -61,6 +78,31 @@ pub fn UseSet() -> Html {
                             }
                         })
                     }
+                    <p>
+                        {
+                            for ["Mercury", "Venus", "Earth", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune"].into_iter().map(|planet| {
+                                let onchange = {
+                                    let checked = checked.clone();
+                                    Callback::from(move |_| {
+                                        let _ = checked.toggle(planet);
+                                    })
+                                };
+                                html! {
+                                    <label class="mr-2">
+                                        <input type="checkbox" checked={checked.contains(&planet)} {onchange} />
+                                        { planet }
+                                    </label>
+                                }
+                            })
+                        }
+                    </p>
+                    <Button onclick={onunion}>{ "Add gas giants" }</Button>
+                    <Button onclick={onintersect}>{ "Only gas giants" }</Button>
+                    <Button onclick={ondifference}>{ "Remove gas giants" }</Button>
+                    <p>
+                        <b>{ "Checked (sorted): " }</b>
+                        { checked.current().iter().cloned().collect::<Vec<_>>().join(", ") }
+                    </p>
                 </div>
             </header>
         </div>
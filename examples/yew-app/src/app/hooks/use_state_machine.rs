@@ -0,0 +1,78 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Upload {
+    Idle,
+    Uploading,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Start,
+    Succeed,
+    Fail,
+    Retry,
+}
+
+/// `use_state_machine` demo
+#[function_component]
+pub fn UseStateMachine() -> Html {
+    let retries = use_mut_ref(|| 0);
+    let upload = {
+        let guard_retries = retries.clone();
+        let exit_retries = retries.clone();
+        use_state_machine(
+            Upload::Idle,
+            StateMachine::from_table(vec![
+                (Upload::Idle, Event::Start, Upload::Uploading),
+                (Upload::Uploading, Event::Succeed, Upload::Done),
+                (Upload::Uploading, Event::Fail, Upload::Failed),
+                (Upload::Failed, Event::Retry, Upload::Uploading),
+            ])
+            // Allow at most 2 retries.
+            .guard(move |_, event, _| *event != Event::Retry || *guard_retries.borrow() < 2)
+            .on_exit(Upload::Failed, move |_| *exit_retries.borrow_mut() += 1),
+        )
+    };
+
+    let send = |event: Event| {
+        let upload = upload.clone();
+        Callback::from(move |_| upload.send(event.clone()))
+    };
+    let onreset = {
+        let upload = upload.clone();
+        let retries = retries.clone();
+        Callback::from(move |_| {
+            *retries.borrow_mut() = 0;
+            upload.reset();
+        })
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={send(Event::Start)} disabled={!upload.can(&Event::Start)}>{ "Start" }</Button>
+                    <Button onclick={send(Event::Succeed)} disabled={!upload.can(&Event::Succeed)}>{ "Succeed" }</Button>
+                    <Button onclick={send(Event::Fail)} disabled={!upload.can(&Event::Fail)}>{ "Fail" }</Button>
+                    <Button onclick={send(Event::Retry)} disabled={!upload.can(&Event::Retry)}>{ "Retry" }</Button>
+                    <Button onclick={onreset}>{ "Reset" }</Button>
+                    <p>
+                        <b>{ "Current state: " }</b>
+                        { format!("{:?}", *upload) }
+                    </p>
+                    {
+                        for upload.log().iter().map(|t| html! {
+                            <p>{ format!("{:?} --{:?}--> {:?}", t.from, t.event, t.to) }</p>
+                        })
+                    }
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -0,0 +1,63 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+/// `use_storage` demo
+#[function_component]
+pub fn UseStorage() -> Html {
+    let counter = use_counter(0);
+    let storage = use_storage_with_options::<_, String>(
+        LocalStorageBackend,
+        "note".to_string(),
+        UseStorageOptions::with_prefix("yew-hooks-demo:"),
+    );
+    let memory = use_storage::<MemoryStorageBackend, i32>("clicks".to_string());
+    let keys = use_storage_keys(LocalStorageBackend, "yew-hooks-demo:".to_string());
+
+    let oninput = {
+        let storage = storage.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            storage.set(input.value());
+        })
+    };
+    let onclick = {
+        let memory = memory.clone();
+        let counter = counter.clone();
+        Callback::from(move |_| {
+            counter.increase();
+            memory.set(*counter + 1);
+        })
+    };
+    let onclear = {
+        let keys = keys.clone();
+        Callback::from(move |_| keys.clear())
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <input
+                        class="flex h-10 w-full rounded-md border border-input bg-transparent px-3 py-2 text-sm"
+                        placeholder="Note, persisted in localStorage with a prefix"
+                        value={(*storage).clone().unwrap_or_default()}
+                        {oninput}
+                    />
+                    <Button onclick={onclick}>{ "Click" }</Button>
+                    <Button onclick={onclear}>{ "Clear prefixed keys" }</Button>
+                    <p>
+                        <b>{ "Clicks kept in memory: " }</b>
+                        { memory.unwrap_or_default() }
+                    </p>
+                    <p>
+                        <b>{ "Keys with prefix: " }</b>
+                        { keys.join(", ") }
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
@@ -10,11 +10,13 @@ pub fn UseThrottle() -> Html {
 
     let throttle = {
         let state = state.clone();
-        use_throttle(
+        // Also counts the last click made while throttled.
+        use_throttle_with_options(
             move || {
                 state.set(*state + 1);
             },
             2000,
+            UseThrottleOptions::trailing(),
         )
     };
 
@@ -23,6 +25,11 @@ pub fn UseThrottle() -> Html {
         Callback::from(move |_| throttle.run())
     };
 
+    let onflush = {
+        let throttle = throttle.clone();
+        Callback::from(move |_| throttle.flush())
+    };
+
     let oncancel = { Callback::from(move |_| throttle.cancel()) };
 
     html! {
@@ -30,6 +37,7 @@ pub fn UseThrottle() -> Html {
             <header class="mt-24 text-xl text-center">
                 <div class="space-x-4 space-y-4">
                     <Button {onclick}>{ "Click fast!" }</Button>
+                    <Button onclick={onflush}>{ "Flush throttle" }</Button>
                     <Button onclick={oncancel}>{ "Cancel throttle" }</Button>
                     <p>
                         <b>{ "State: " }</b> {*state}
@@ -0,0 +1,61 @@
+use yew::prelude::*;
+use yew_hooks::prelude::*;
+
+use crate::components::ui::button::Button;
+
+#[derive(Debug, Properties, PartialEq, Clone)]
+struct ProfileProps {
+    pub name: String,
+    pub visits: i32,
+    pub tags: Vec<String>,
+}
+
+#[function_component]
+fn Profile(props: &ProfileProps) -> Html {
+    use_why_did_you_update("Profile".to_string(), props.clone());
+
+    html! {
+        <p>
+            <b>{ " name: " }</b> { &props.name }
+            <b>{ " visits: " }</b> { props.visits }
+            <b>{ " tags: " }</b> { props.tags.join(", ") }
+        </p>
+    }
+}
+
+/// `use_why_did_you_update` demo
+#[function_component]
+pub fn UseWhyDidYouUpdate() -> Html {
+    let name = use_toggle("Alice", "Bob");
+    let visits = use_counter(0);
+    let tags = use_list(vec!["new".to_string()]);
+
+    let onname = {
+        let name = name.clone();
+        Callback::from(move |_| name.toggle())
+    };
+    let onvisit = {
+        let visits = visits.clone();
+        Callback::from(move |_| visits.increase())
+    };
+    let ontag = {
+        let tags = tags.clone();
+        Callback::from(move |_| tags.push("active".to_string()))
+    };
+
+    html! {
+        <div class="container">
+            <header class="mt-24 text-xl text-center">
+                <div class="space-x-4 space-y-4">
+                    <Button onclick={onname}>{ "Toggle name" }</Button>
+                    <Button onclick={onvisit}>{ "Visit" }</Button>
+                    <Button onclick={ontag}>{ "Add tag" }</Button>
+                    <Profile name={name.to_string()} visits={*visits} tags={tags.current().clone()} />
+                    <p>
+                        <b>{ "Please open the browser console to view the output!" }</b>
+                    </p>
+                </div>
+            </header>
+        </div>
+    }
+}
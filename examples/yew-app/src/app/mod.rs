@@ -149,6 +149,40 @@ pub enum AppRoute {
     UseIdle,
     #[at("/use_start_typing")]
     UseStartTyping,
+    #[at("/use_indexed_db")]
+    UseIndexedDb,
+    #[at("/use_encrypted_storage")]
+    UseEncryptedStorage,
+    #[at("/use_storage")]
+    UseStorage,
+    #[at("/use_persisted_list")]
+    UsePersistedList,
+    #[at("/use_history_state")]
+    UseHistoryState,
+    #[at("/use_cycle")]
+    UseCycle,
+    #[at("/use_state_machine")]
+    UseStateMachine,
+    #[at("/use_atom")]
+    UseAtom,
+    #[at("/use_form")]
+    UseForm,
+    #[at("/use_history_of")]
+    UseHistoryOf,
+    #[at("/use_priority_queue")]
+    UsePriorityQueue,
+    #[at("/use_selection")]
+    UseSelection,
+    #[at("/use_debounced_value")]
+    UseDebouncedValue,
+    #[at("/use_effect_with_cmp")]
+    UseEffectWithCmp,
+    #[at("/use_async_effect")]
+    UseAsyncEffect,
+    #[at("/use_why_did_you_update")]
+    UseWhyDidYouUpdate,
+    #[at("/use_render_profiler")]
+    UseRenderProfiler,
     #[not_found]
     #[at("/page-not-found")]
     PageNotFound,
@@ -228,6 +262,23 @@ pub fn switch(routes: AppRoute) -> Html {
         AppRoute::UseCookie => html! { <UseCookie /> },
         AppRoute::UseIdle => html! { <UseIdle /> },
         AppRoute::UseStartTyping => html! { <UseStartTyping /> },
+        AppRoute::UseIndexedDb => html! { <UseIndexedDb /> },
+        AppRoute::UseEncryptedStorage => html! { <UseEncryptedStorage /> },
+        AppRoute::UseStorage => html! { <UseStorage /> },
+        AppRoute::UsePersistedList => html! { <UsePersistedList /> },
+        AppRoute::UseHistoryState => html! { <UseHistoryState /> },
+        AppRoute::UseCycle => html! { <UseCycle /> },
+        AppRoute::UseStateMachine => html! { <UseStateMachine /> },
+        AppRoute::UseAtom => html! { <UseAtom /> },
+        AppRoute::UseForm => html! { <UseForm /> },
+        AppRoute::UseHistoryOf => html! { <UseHistoryOf /> },
+        AppRoute::UsePriorityQueue => html! { <UsePriorityQueue /> },
+        AppRoute::UseSelection => html! { <UseSelection /> },
+        AppRoute::UseDebouncedValue => html! { <UseDebouncedValue /> },
+        AppRoute::UseEffectWithCmp => html! { <UseEffectWithCmp /> },
+        AppRoute::UseAsyncEffect => html! { <UseAsyncEffect /> },
+        AppRoute::UseWhyDidYouUpdate => html! { <UseWhyDidYouUpdate /> },
+        AppRoute::UseRenderProfiler => html! { <UseRenderProfiler /> },
         AppRoute::PageNotFound => html! { <Home /> },
     }
 }
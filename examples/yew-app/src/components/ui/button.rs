@@ -8,6 +8,8 @@ pub struct ButtonProps {
     pub disabled: bool,
     #[prop_or_default]
     pub button_ref: NodeRef,
+    #[prop_or(AttrValue::Static("button"))]
+    pub button_type: AttrValue,
     #[prop_or_default]
     pub class: String,
     #[prop_or_default]
@@ -25,6 +27,7 @@ pub fn Button(props: &ButtonProps) -> Html {
 
     html! {
         <button
+            type={props.button_type.clone()}
             class={combined_classes}
             onclick={props.onclick.clone()}
             disabled={props.disabled}